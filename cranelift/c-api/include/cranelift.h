@@ -0,0 +1,223 @@
+/**
+ * \file cranelift.h
+ *
+ * \brief C API for compiling Cranelift IR.
+ *
+ * This header declares functions to configure shared flags and a target ISA,
+ * compile a function written in the Cranelift IR text format or serialized as
+ * JSON, and retrieve the resulting machine code and relocations.
+ *
+ * All objects returned by this API are owned by the caller and must be
+ * released with the matching `*_delete` function. Functions that can fail
+ * return a #cranelift_error_t pointer which is `NULL` on success. Internal
+ * errors that would otherwise abort, such as panics in the code generator, are
+ * reported as errors as well.
+ */
+
+#ifndef CRANELIFT_H
+#define CRANELIFT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifndef CRANELIFT_API_EXTERN
+#ifdef _WIN32
+#define CRANELIFT_API_EXTERN __declspec(dllimport)
+#else
+#define CRANELIFT_API_EXTERN
+#endif
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \typedef cranelift_error_t
+ * \brief Convenience alias for #cranelift_error
+ *
+ * \struct cranelift_error
+ * \brief An error produced by one of the functions in this API.
+ *
+ * Errors must be deleted with #cranelift_error_delete.
+ */
+typedef struct cranelift_error cranelift_error_t;
+
+/**
+ * \brief Returns the NUL-terminated message of an error.
+ *
+ * The returned string is owned by `error` and is valid until it is deleted.
+ */
+CRANELIFT_API_EXTERN const char *
+cranelift_error_message(const cranelift_error_t *error);
+
+/**
+ * \brief Deletes an error.
+ */
+CRANELIFT_API_EXTERN void cranelift_error_delete(cranelift_error_t *error);
+
+/**
+ * \typedef cranelift_isa_t
+ * \brief Convenience alias for #cranelift_isa
+ *
+ * \struct cranelift_isa
+ * \brief A configured target ISA.
+ *
+ * ISAs must be deleted with #cranelift_isa_delete.
+ */
+typedef struct cranelift_isa cranelift_isa_t;
+
+/**
+ * \brief Creates a target ISA.
+ *
+ * \param triple the NUL-terminated target triple, e.g. `x86_64-unknown-linux`.
+ * \param settings an array of `nsettings` NUL-terminated settings, each of the
+ *        form `name=value`, or just `name` to enable a boolean setting. Both
+ *        shared and ISA-specific settings are accepted.
+ * \param nsettings the number of entries in `settings`.
+ * \param ret where the new ISA is stored on success.
+ *
+ * \return `NULL` on success, or an error otherwise.
+ */
+CRANELIFT_API_EXTERN cranelift_error_t *
+cranelift_isa_new(const char *triple, const char *const *settings,
+                  size_t nsettings, cranelift_isa_t **ret);
+
+/**
+ * \typedef cranelift_flags_t
+ * \brief Convenience alias for #cranelift_flags
+ *
+ * \struct cranelift_flags
+ * \brief A set of shared settings, independent of the target ISA.
+ *
+ * Flags must be deleted with #cranelift_flags_delete.
+ */
+typedef struct cranelift_flags cranelift_flags_t;
+
+/**
+ * \brief Creates a set of shared settings.
+ *
+ * \param settings an array of `nsettings` NUL-terminated settings, each of the
+ *        form `name=value`, or just `name` to enable a boolean setting.
+ *        Settings that aren't given keep their default values.
+ * \param nsettings the number of entries in `settings`.
+ * \param ret where the new flags are stored on success.
+ *
+ * \return `NULL` on success, or an error otherwise.
+ */
+CRANELIFT_API_EXTERN cranelift_error_t *
+cranelift_flags_new(const char *const *settings, size_t nsettings,
+                    cranelift_flags_t **ret);
+
+/**
+ * \brief Deletes a set of shared settings.
+ */
+CRANELIFT_API_EXTERN void cranelift_flags_delete(cranelift_flags_t *flags);
+
+/**
+ * \brief Creates a target ISA from shared flags and ISA-specific settings.
+ *
+ * \param triple the NUL-terminated target triple, e.g. `x86_64-unknown-linux`.
+ * \param flags the shared settings, which are copied into the ISA.
+ * \param isa_settings an array of `nisa_settings` NUL-terminated ISA-specific
+ *        settings, of the same form as in #cranelift_flags_new.
+ * \param nisa_settings the number of entries in `isa_settings`.
+ * \param ret where the new ISA is stored on success.
+ *
+ * \return `NULL` on success, or an error otherwise.
+ */
+CRANELIFT_API_EXTERN cranelift_error_t *
+cranelift_isa_new_with_flags(const char *triple, const cranelift_flags_t *flags,
+                             const char *const *isa_settings,
+                             size_t nisa_settings, cranelift_isa_t **ret);
+
+/**
+ * \brief Deletes a target ISA.
+ */
+CRANELIFT_API_EXTERN void cranelift_isa_delete(cranelift_isa_t *isa);
+
+/**
+ * \brief A relocation in the code of a compiled function.
+ */
+typedef struct cranelift_reloc {
+  /// Offset of the relocation from the start of the function's code.
+  uint32_t offset;
+  /// NUL-terminated name of the relocation kind, e.g. `Abs8`.
+  const char *kind;
+  /// NUL-terminated name of the relocation's target, e.g. `u0:1`.
+  const char *target;
+  /// Addend to apply to the target's address.
+  int64_t addend;
+} cranelift_reloc_t;
+
+/**
+ * \typedef cranelift_compiled_function_t
+ * \brief Convenience alias for #cranelift_compiled_function
+ *
+ * \struct cranelift_compiled_function
+ * \brief The machine code and relocations of a compiled function.
+ *
+ * Compiled functions must be deleted with #cranelift_compiled_function_delete.
+ */
+typedef struct cranelift_compiled_function cranelift_compiled_function_t;
+
+/**
+ * \brief Parses and compiles a single function.
+ *
+ * \param isa the target to compile for.
+ * \param clif the UTF-8 text of exactly one function in the Cranelift IR text
+ *        format, which need not be NUL-terminated.
+ * \param len the length of `clif` in bytes.
+ * \param ret where the compiled function is stored on success.
+ *
+ * \return `NULL` on success, or an error otherwise.
+ */
+CRANELIFT_API_EXTERN cranelift_error_t *
+cranelift_compile_clif(const cranelift_isa_t *isa, const uint8_t *clif,
+                       size_t len, cranelift_compiled_function_t **ret);
+
+/**
+ * \brief Deserializes and compiles a single function.
+ *
+ * \param isa the target to compile for.
+ * \param json the UTF-8 JSON text of an array holding exactly one function,
+ *        as written by `clif-json serialize`, which need not be NUL-terminated.
+ * \param len the length of `json` in bytes.
+ * \param ret where the compiled function is stored on success.
+ *
+ * \return `NULL` on success, or an error otherwise.
+ */
+CRANELIFT_API_EXTERN cranelift_error_t *
+cranelift_compile_json(const cranelift_isa_t *isa, const uint8_t *json,
+                       size_t len, cranelift_compiled_function_t **ret);
+
+/**
+ * \brief Returns the machine code of a compiled function.
+ *
+ * The returned buffer is owned by `func` and is valid until it is deleted.
+ */
+CRANELIFT_API_EXTERN void
+cranelift_compiled_function_code(const cranelift_compiled_function_t *func,
+                                 const uint8_t **data, size_t *len);
+
+/**
+ * \brief Returns the relocations of a compiled function.
+ *
+ * The returned array, and the strings it points to, are owned by `func` and
+ * are valid until it is deleted.
+ */
+CRANELIFT_API_EXTERN void
+cranelift_compiled_function_relocs(const cranelift_compiled_function_t *func,
+                                   const cranelift_reloc_t **data, size_t *len);
+
+/**
+ * \brief Deletes a compiled function.
+ */
+CRANELIFT_API_EXTERN void
+cranelift_compiled_function_delete(cranelift_compiled_function_t *func);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // CRANELIFT_H
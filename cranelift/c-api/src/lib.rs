@@ -0,0 +1,319 @@
+//! C API for compiling Cranelift IR.
+//!
+//! This crate exposes `extern "C"` functions, declared in `include/cranelift.h`, that let
+//! non-Rust runtimes configure shared flags and a target ISA, compile functions written in the
+//! Cranelift IR text format or serialized as JSON, and retrieve the resulting machine code and
+//! relocations.
+//!
+//! All objects handed out to C are boxed and must be released with the matching `*_delete`
+//! function. Functions that can fail return a `cranelift_error_t` pointer, which is null on
+//! success. Panics are caught and reported as errors too, so they never unwind into C.
+
+#![allow(non_camel_case_types)]
+
+use anyhow::{anyhow, bail, Result};
+use cranelift_codegen::ir::Function;
+use cranelift_codegen::isa::{self, OwnedTargetIsa};
+use cranelift_codegen::settings::{self, Configurable, Flags};
+use cranelift_codegen::Context;
+use cranelift_control::ControlPlane;
+use cranelift_reader::{parse_functions, parse_sets_and_triple, OwnedFlagsOrIsa};
+use std::any::Any;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::str::FromStr;
+use target_lexicon::Triple;
+
+/// An error produced by one of the functions in this API.
+pub struct cranelift_error_t {
+    message: CString,
+}
+
+impl From<anyhow::Error> for cranelift_error_t {
+    fn from(error: anyhow::Error) -> Self {
+        // Interior NULs can't be represented in a C string, so replace them.
+        let message = format!("{:?}", error).replace('\0', "\u{FFFD}");
+        cranelift_error_t {
+            message: CString::new(message).unwrap_or_default(),
+        }
+    }
+}
+
+/// Run `f`, passing its result to `ok` on success, and turn both errors and panics into a
+/// `cranelift_error_t`.
+fn handle_result<T>(
+    f: impl FnOnce() -> Result<T>,
+    ok: impl FnOnce(T),
+) -> Option<Box<cranelift_error_t>> {
+    let result = panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(anyhow!("panic: {}", panic_message(&*payload))));
+    match result {
+        Ok(value) => {
+            ok(value);
+            None
+        }
+        Err(error) => Some(Box::new(error.into())),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic payload"
+    }
+}
+
+/// Collect `n` NUL-terminated strings from the C array `strings`.
+unsafe fn c_strings<'a>(strings: *const *const c_char, n: usize) -> Result<Vec<&'a str>> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    slice::from_raw_parts(strings, n)
+        .iter()
+        .map(|s| Ok(CStr::from_ptr(*s).to_str()?))
+        .collect()
+}
+
+/// Apply a setting of the form `name=value`, or just `name` to enable a boolean setting.
+fn configure(config: &mut dyn Configurable, setting: &str) -> Result<()> {
+    match setting.split_once('=') {
+        Some((name, value)) => config.set(name.trim(), value.trim())?,
+        None => config.enable(setting.trim())?,
+    }
+    Ok(())
+}
+
+/// Returns the NUL-terminated message of `error`, owned by `error`.
+#[no_mangle]
+pub extern "C" fn cranelift_error_message(error: &cranelift_error_t) -> *const c_char {
+    error.message.as_ptr()
+}
+
+/// Deletes an error.
+#[no_mangle]
+pub extern "C" fn cranelift_error_delete(_error: Box<cranelift_error_t>) {}
+
+/// A configured target ISA.
+pub struct cranelift_isa_t {
+    isa: OwnedTargetIsa,
+}
+
+/// Creates a target ISA for `triple` with the given settings.
+///
+/// Each setting is a NUL-terminated string of the form `name=value`, or just `name` to enable a
+/// boolean setting, and may be either a shared or an ISA-specific setting.
+#[no_mangle]
+pub unsafe extern "C" fn cranelift_isa_new(
+    triple: *const c_char,
+    settings: *const *const c_char,
+    nsettings: usize,
+    ret: &mut *mut cranelift_isa_t,
+) -> Option<Box<cranelift_error_t>> {
+    handle_result(
+        || {
+            let triple = CStr::from_ptr(triple).to_str()?;
+            let settings: Vec<String> = c_strings(settings, nsettings)?
+                .into_iter()
+                .map(String::from)
+                .collect();
+            match parse_sets_and_triple(&settings, triple)? {
+                OwnedFlagsOrIsa::Isa(isa) => Ok(isa),
+                OwnedFlagsOrIsa::Flags(_) => bail!("a target triple is required"),
+            }
+        },
+        |isa| *ret = Box::into_raw(Box::new(cranelift_isa_t { isa })),
+    )
+}
+
+/// Creates a target ISA for `triple` with the given shared `flags` and ISA-specific settings.
+///
+/// Each ISA-specific setting has the same form as in `cranelift_flags_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cranelift_isa_new_with_flags(
+    triple: *const c_char,
+    flags: &cranelift_flags_t,
+    isa_settings: *const *const c_char,
+    nisa_settings: usize,
+    ret: &mut *mut cranelift_isa_t,
+) -> Option<Box<cranelift_error_t>> {
+    handle_result(
+        || {
+            let triple = CStr::from_ptr(triple).to_str()?;
+            let mut builder = isa::lookup(Triple::from_str(triple).map_err(|e| anyhow!("{e}"))?)?;
+            for setting in c_strings(isa_settings, nisa_settings)? {
+                configure(&mut builder, setting)?;
+            }
+            Ok(builder.finish(flags.flags.clone())?)
+        },
+        |isa| *ret = Box::into_raw(Box::new(cranelift_isa_t { isa })),
+    )
+}
+
+/// Deletes a target ISA.
+#[no_mangle]
+pub extern "C" fn cranelift_isa_delete(_isa: Box<cranelift_isa_t>) {}
+
+/// A set of shared settings, independent of the target ISA.
+pub struct cranelift_flags_t {
+    flags: Flags,
+}
+
+/// Creates a set of shared settings.
+///
+/// Each setting is a NUL-terminated string of the form `name=value`, or just `name` to enable a
+/// boolean setting. Settings that aren't given keep their default values.
+#[no_mangle]
+pub unsafe extern "C" fn cranelift_flags_new(
+    settings: *const *const c_char,
+    nsettings: usize,
+    ret: &mut *mut cranelift_flags_t,
+) -> Option<Box<cranelift_error_t>> {
+    handle_result(
+        || {
+            let mut builder = settings::builder();
+            for setting in c_strings(settings, nsettings)? {
+                configure(&mut builder, setting)?;
+            }
+            Ok(Flags::new(builder))
+        },
+        |flags| *ret = Box::into_raw(Box::new(cranelift_flags_t { flags })),
+    )
+}
+
+/// Deletes a set of shared settings.
+#[no_mangle]
+pub extern "C" fn cranelift_flags_delete(_flags: Box<cranelift_flags_t>) {}
+
+/// A relocation in the code of a compiled function.
+#[repr(C)]
+pub struct cranelift_reloc_t {
+    /// Offset of the relocation from the start of the function's code.
+    pub offset: u32,
+    /// NUL-terminated name of the relocation kind, e.g. `Abs8` or `X86CallPCRel4`.
+    pub kind: *const c_char,
+    /// NUL-terminated name of the relocation's target, in the IR text format, e.g. `u0:1`.
+    pub target: *const c_char,
+    /// Addend to apply to the target's address.
+    pub addend: i64,
+}
+
+/// The machine code and relocations of a compiled function.
+pub struct cranelift_compiled_function_t {
+    code: Vec<u8>,
+    relocs: Vec<cranelift_reloc_t>,
+    // Backing storage for the `kind` and `target` strings in `relocs`.
+    _strings: Vec<CString>,
+}
+
+fn single_function(mut funcs: Vec<Function>) -> Result<Function> {
+    if funcs.len() != 1 {
+        bail!("expected exactly one function, found {}", funcs.len());
+    }
+    Ok(funcs.pop().unwrap())
+}
+
+fn compile_function(
+    isa: &cranelift_isa_t,
+    func: Function,
+) -> Result<cranelift_compiled_function_t> {
+    let mut ctx = Context::for_function(func);
+    ctx.compile(&*isa.isa, &mut ControlPlane::default())
+        .map_err(|err| anyhow!("{}", err.inner))?;
+    let compiled = ctx.compiled_code().unwrap();
+
+    let mut strings = Vec::new();
+    let mut relocs = Vec::new();
+    for reloc in compiled.buffer.relocs() {
+        let kind = CString::new(reloc.kind.to_string())?;
+        let target = CString::new(reloc.target.display(Some(&ctx.func.params)))?;
+        relocs.push(cranelift_reloc_t {
+            offset: reloc.offset,
+            kind: kind.as_ptr(),
+            target: target.as_ptr(),
+            addend: reloc.addend,
+        });
+        // Moving a `CString` doesn't move its heap buffer, so the pointers stay valid.
+        strings.push(kind);
+        strings.push(target);
+    }
+
+    Ok(cranelift_compiled_function_t {
+        code: compiled.code_buffer().to_vec(),
+        relocs,
+        _strings: strings,
+    })
+}
+
+/// Parses a single function in the Cranelift IR text format and compiles it for `isa`.
+#[no_mangle]
+pub unsafe extern "C" fn cranelift_compile_clif(
+    isa: &cranelift_isa_t,
+    clif: *const u8,
+    len: usize,
+    ret: &mut *mut cranelift_compiled_function_t,
+) -> Option<Box<cranelift_error_t>> {
+    handle_result(
+        || {
+            let clif = std::str::from_utf8(c_bytes(clif, len))?;
+            compile_function(isa, single_function(parse_functions(clif)?)?)
+        },
+        |compiled| *ret = Box::into_raw(Box::new(compiled)),
+    )
+}
+
+/// Deserializes a single function from JSON and compiles it for `isa`.
+///
+/// The JSON is an array holding exactly one function, as written by `clif-json serialize`.
+#[no_mangle]
+pub unsafe extern "C" fn cranelift_compile_json(
+    isa: &cranelift_isa_t,
+    json: *const u8,
+    len: usize,
+    ret: &mut *mut cranelift_compiled_function_t,
+) -> Option<Box<cranelift_error_t>> {
+    handle_result(
+        || {
+            let funcs: Vec<Function> = serde_json::from_slice(c_bytes(json, len))?;
+            compile_function(isa, single_function(funcs)?)
+        },
+        |compiled| *ret = Box::into_raw(Box::new(compiled)),
+    )
+}
+
+unsafe fn c_bytes<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len)
+    }
+}
+
+/// Returns the machine code of a compiled function, owned by `func`.
+#[no_mangle]
+pub extern "C" fn cranelift_compiled_function_code(
+    func: &cranelift_compiled_function_t,
+    data: &mut *const u8,
+    len: &mut usize,
+) {
+    *data = func.code.as_ptr();
+    *len = func.code.len();
+}
+
+/// Returns the relocations of a compiled function, owned by `func`.
+#[no_mangle]
+pub extern "C" fn cranelift_compiled_function_relocs(
+    func: &cranelift_compiled_function_t,
+    data: &mut *const cranelift_reloc_t,
+    len: &mut usize,
+) {
+    *data = func.relocs.as_ptr();
+    *len = func.relocs.len();
+}
+
+/// Deletes a compiled function.
+#[no_mangle]
+pub extern "C" fn cranelift_compiled_function_delete(_func: Box<cranelift_compiled_function_t>) {}
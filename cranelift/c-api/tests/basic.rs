@@ -0,0 +1,186 @@
+use cranelift_c_api::*;
+use cranelift_reader::parse_functions;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+const TRIPLE: &str = "x86_64-unknown-linux-gnu";
+
+const CALL: &str = "
+function %f(i64) -> i64 {
+    fn0 = %g(i64) -> i64
+
+block0(v0: i64):
+    v1 = call fn0(v0)
+    return v1
+}
+";
+
+/// Turns a returned error into a panic carrying its message.
+fn check(error: Option<Box<cranelift_error_t>>) {
+    if let Some(error) = error {
+        let message = unsafe { CStr::from_ptr(cranelift_error_message(&error)) };
+        panic!("{}", message.to_str().unwrap());
+    }
+}
+
+/// Returns the message of an error that must have been returned.
+fn expect_error(error: Option<Box<cranelift_error_t>>) -> String {
+    let error = error.expect("expected an error");
+    let message = unsafe { CStr::from_ptr(cranelift_error_message(&error)) };
+    let message = message.to_str().unwrap().to_string();
+    cranelift_error_delete(error);
+    message
+}
+
+fn c_strings(strings: &[&str]) -> (Vec<CString>, Vec<*const c_char>) {
+    let owned: Vec<CString> = strings.iter().map(|s| CString::new(*s).unwrap()).collect();
+    let ptrs = owned.iter().map(|s| s.as_ptr()).collect();
+    (owned, ptrs)
+}
+
+fn new_isa(settings: &[&str]) -> Box<cranelift_isa_t> {
+    let triple = CString::new(TRIPLE).unwrap();
+    let (_owned, settings) = c_strings(settings);
+    let mut isa = ptr::null_mut();
+    unsafe {
+        check(cranelift_isa_new(
+            triple.as_ptr(),
+            settings.as_ptr(),
+            settings.len(),
+            &mut isa,
+        ));
+        Box::from_raw(isa)
+    }
+}
+
+fn compile_clif(
+    isa: &cranelift_isa_t,
+    clif: &str,
+) -> Result<Box<cranelift_compiled_function_t>, String> {
+    let mut func = ptr::null_mut();
+    match unsafe { cranelift_compile_clif(isa, clif.as_ptr(), clif.len(), &mut func) } {
+        Some(error) => Err(expect_error(Some(error))),
+        None => Ok(unsafe { Box::from_raw(func) }),
+    }
+}
+
+fn code(func: &cranelift_compiled_function_t) -> &[u8] {
+    let (mut data, mut len) = (ptr::null(), 0);
+    cranelift_compiled_function_code(func, &mut data, &mut len);
+    unsafe { slice::from_raw_parts(data, len) }
+}
+
+fn relocs(func: &cranelift_compiled_function_t) -> Vec<(String, String)> {
+    let (mut data, mut len) = (ptr::null(), 0);
+    cranelift_compiled_function_relocs(func, &mut data, &mut len);
+    unsafe { slice::from_raw_parts(data, len) }
+        .iter()
+        .map(|reloc| unsafe {
+            (
+                CStr::from_ptr(reloc.kind).to_str().unwrap().to_string(),
+                CStr::from_ptr(reloc.target).to_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn compile_clif_with_call() {
+    let isa = new_isa(&["opt_level=speed"]);
+    let func = compile_clif(&isa, CALL).unwrap();
+    assert!(!code(&func).is_empty());
+    assert_eq!(relocs(&func), [("Abs8".to_string(), "%g".to_string())]);
+    cranelift_compiled_function_delete(func);
+    cranelift_isa_delete(isa);
+}
+
+#[test]
+fn compile_clif_errors() {
+    let isa = new_isa(&[]);
+    let message = compile_clif(&isa, "function %f() {")
+        .err()
+        .expect("expected an error");
+    assert!(message.contains("expected"), "{message}");
+    let message = compile_clif(&isa, &format!("{CALL}{CALL}"))
+        .err()
+        .expect("expected an error");
+    assert!(message.contains("exactly one function"), "{message}");
+    cranelift_isa_delete(isa);
+}
+
+#[test]
+fn isa_new_errors() {
+    let triple = CString::new(TRIPLE).unwrap();
+    let (_owned, settings) = c_strings(&["no_such_setting"]);
+    let mut isa = ptr::null_mut();
+    let error =
+        unsafe { cranelift_isa_new(triple.as_ptr(), settings.as_ptr(), settings.len(), &mut isa) };
+    expect_error(error);
+    assert!(isa.is_null());
+}
+
+#[test]
+fn isa_with_flags() {
+    let (_owned, settings) = c_strings(&["opt_level=speed", "enable_verifier"]);
+    let mut flags = ptr::null_mut();
+    let flags = unsafe {
+        check(cranelift_flags_new(
+            settings.as_ptr(),
+            settings.len(),
+            &mut flags,
+        ));
+        Box::from_raw(flags)
+    };
+
+    let triple = CString::new(TRIPLE).unwrap();
+    let (_owned, isa_settings) = c_strings(&["has_sse41"]);
+    let mut isa = ptr::null_mut();
+    let isa = unsafe {
+        check(cranelift_isa_new_with_flags(
+            triple.as_ptr(),
+            &flags,
+            isa_settings.as_ptr(),
+            isa_settings.len(),
+            &mut isa,
+        ));
+        Box::from_raw(isa)
+    };
+    cranelift_flags_delete(flags);
+
+    let func = compile_clif(&isa, CALL).unwrap();
+    assert!(!code(&func).is_empty());
+    cranelift_compiled_function_delete(func);
+    cranelift_isa_delete(isa);
+
+    // Invalid values are reported rather than ignored.
+    let (_owned, settings) = c_strings(&["opt_level=bogus"]);
+    let mut flags = ptr::null_mut();
+    expect_error(unsafe { cranelift_flags_new(settings.as_ptr(), settings.len(), &mut flags) });
+}
+
+#[test]
+fn compile_json() {
+    let isa = new_isa(&[]);
+    let json = serde_json::to_string(&parse_functions(CALL).unwrap()).unwrap();
+    let mut func = ptr::null_mut();
+    let func = unsafe {
+        check(cranelift_compile_json(
+            &isa,
+            json.as_ptr(),
+            json.len(),
+            &mut func,
+        ));
+        Box::from_raw(func)
+    };
+    assert!(!code(&func).is_empty());
+    assert_eq!(relocs(&func).len(), 1);
+    cranelift_compiled_function_delete(func);
+
+    let mut func = ptr::null_mut();
+    let message =
+        expect_error(unsafe { cranelift_compile_json(&isa, b"[".as_ptr(), 1, &mut func) });
+    assert!(message.contains("EOF"), "{message}");
+    cranelift_isa_delete(isa);
+}
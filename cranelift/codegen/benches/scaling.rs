@@ -0,0 +1,155 @@
+//! Measure how the core analyses and the full compilation pipeline scale with
+//! function size, using large synthetic functions.
+//!
+//! The functions are generated deterministically from a seed, so runs are
+//! comparable. Compare the timings across the sizes in each group: per-block
+//! time that grows with the block count points at a quadratic algorithm.
+
+use cranelift_codegen::cursor::{Cursor, FuncCursor};
+use cranelift_codegen::dominator_tree::DominatorTree;
+use cranelift_codegen::flowgraph::ControlFlowGraph;
+use cranelift_codegen::ir::{
+    types::I32, AbiParam, Block, Function, InstBuilder, Signature, UserFuncName, Value,
+};
+use cranelift_codegen::isa::CallConv;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Parameters of a synthetic function.
+#[derive(Clone, Copy)]
+struct Shape {
+    /// Number of blocks in the function.
+    blocks: usize,
+    /// Number of non-terminator instructions in each block.
+    insts_per_block: usize,
+    /// Percentage of blocks that end in a conditional branch rather than a jump.
+    branch_percent: u32,
+}
+
+/// A small xorshift generator, so the generated functions don't depend on an
+/// external crate.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 32) as u32
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        self.next() as usize % n
+    }
+}
+
+/// Generate a function of the given `shape`.
+///
+/// Every block computes a chain of integer operations from the function's
+/// parameter, so all operands trivially dominate their uses regardless of the
+/// control flow. Conditional branches target the next block and a random
+/// non-entry block, which creates both forward edges and loops.
+fn synthetic_function(shape: Shape, seed: u64) -> Function {
+    let mut rng = Rng(seed | 1);
+    let mut sig = Signature::new(CallConv::SystemV);
+    sig.params.push(AbiParam::new(I32));
+    sig.returns.push(AbiParam::new(I32));
+    let mut func = Function::with_name_signature(UserFuncName::testcase("synthetic"), sig);
+
+    let blocks: Vec<Block> = (0..shape.blocks).map(|_| func.dfg.make_block()).collect();
+    let param = func.dfg.append_block_param(blocks[0], I32);
+
+    let mut pos = FuncCursor::new(&mut func);
+    for (i, &block) in blocks.iter().enumerate() {
+        pos.insert_block(block);
+
+        let mut values: Vec<Value> = vec![param];
+        for _ in 0..shape.insts_per_block {
+            let x = values[rng.below(values.len())];
+            let y = values[rng.below(values.len())];
+            let v = match rng.below(6) {
+                0 => pos.ins().iadd(x, y),
+                1 => pos.ins().isub(x, y),
+                2 => pos.ins().imul(x, y),
+                3 => pos.ins().bxor(x, y),
+                4 => pos.ins().ishl_imm(x, i64::from(rng.next() % 32)),
+                _ => pos.ins().iadd_imm(x, i64::from(rng.next())),
+            };
+            values.push(v);
+        }
+        let last = *values.last().unwrap();
+
+        if i + 1 == shape.blocks {
+            pos.ins().return_(&[last]);
+        } else if rng.next() % 100 < shape.branch_percent {
+            let other = blocks[1 + rng.below(shape.blocks - 1)];
+            pos.ins().brif(last, blocks[i + 1], &[], other, &[]);
+        } else {
+            pos.ins().jump(blocks[i + 1], &[]);
+        }
+    }
+
+    func
+}
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn shape(blocks: usize) -> Shape {
+    Shape {
+        blocks,
+        insts_per_block: 8,
+        branch_percent: 50,
+    }
+}
+
+fn analysis_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("analysis scaling");
+    for blocks in SIZES {
+        let func = synthetic_function(shape(blocks), 0x5eed);
+        group.throughput(Throughput::Elements(blocks as u64));
+
+        group.bench_with_input(BenchmarkId::new("cfg", blocks), &func, |b, func| {
+            b.iter(|| ControlFlowGraph::with_function(func));
+        });
+        group.bench_with_input(BenchmarkId::new("domtree", blocks), &func, |b, func| {
+            let cfg = ControlFlowGraph::with_function(func);
+            b.iter(|| DominatorTree::with_function(func, &cfg));
+        });
+    }
+    group.finish();
+}
+
+#[cfg(feature = "x86")]
+fn compile_benchmarks(c: &mut Criterion) {
+    use cranelift_codegen::settings::{self, Configurable};
+    use cranelift_codegen::Context;
+    use cranelift_control::ControlPlane;
+    use target_lexicon::triple;
+
+    let mut flags = settings::builder();
+    flags.set("opt_level", "speed").unwrap();
+    let isa = cranelift_codegen::isa::lookup(triple!("x86_64"))
+        .unwrap()
+        .finish(settings::Flags::new(flags))
+        .unwrap();
+
+    let mut group = c.benchmark_group("compile scaling");
+    group.sample_size(10);
+    for blocks in SIZES {
+        let func = synthetic_function(shape(blocks), 0x5eed);
+        group.throughput(Throughput::Elements(blocks as u64));
+
+        group.bench_with_input(BenchmarkId::new("x86_64", blocks), &func, |b, func| {
+            b.iter(|| {
+                let mut ctx = Context::for_function(func.clone());
+                ctx.compile(&*isa, &mut ControlPlane::default()).unwrap();
+            });
+        });
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "x86"))]
+fn compile_benchmarks(_c: &mut Criterion) {}
+
+criterion_group!(benches, analysis_benchmarks, compile_benchmarks);
+criterion_main!(benches);
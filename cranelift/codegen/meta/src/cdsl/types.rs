@@ -136,6 +136,11 @@ impl LaneType {
     /// Return a string containing the documentation comment for this lane type.
     pub fn doc(self) -> String {
         match self {
+            LaneType::Float(shared_types::Float::F16) => String::from(
+                "A 16-bit floating point type represented in the IEEE 754-2008
+                *binary16* interchange format. This is a storage-only type: it can be
+                loaded, stored, and converted to and from the wider float types.",
+            ),
             LaneType::Float(shared_types::Float::F32) => String::from(
                 "A 32-bit floating point type represented in the IEEE 754-2008
                 *binary32* interchange format. This corresponds to the :c:type:`float`
@@ -173,8 +178,9 @@ impl LaneType {
                 LaneType::Int(shared_types::Int::I32) => 8,
                 LaneType::Int(shared_types::Int::I64) => 9,
                 LaneType::Int(shared_types::Int::I128) => 10,
-                LaneType::Float(shared_types::Float::F32) => 11,
-                LaneType::Float(shared_types::Float::F64) => 12,
+                LaneType::Float(shared_types::Float::F16) => 11,
+                LaneType::Float(shared_types::Float::F32) => 12,
+                LaneType::Float(shared_types::Float::F64) => 13,
             }
     }
 
@@ -191,6 +197,7 @@ impl LaneType {
 
     pub fn float_from_bits(num_bits: u16) -> LaneType {
         LaneType::Float(match num_bits {
+            16 => shared_types::Float::F16,
             32 => shared_types::Float::F32,
             64 => shared_types::Float::F64,
             _ => unreachable!("unxpected num bits for float"),
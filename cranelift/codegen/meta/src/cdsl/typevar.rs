@@ -10,6 +10,9 @@ use crate::cdsl::types::{LaneType, ReferenceType, ValueType};
 const MAX_LANES: u16 = 256;
 const MAX_BITS: u16 = 128;
 const MAX_FLOAT_BITS: u16 = 64;
+/// `f16` is a storage-only type, so it is only part of a type set whose float range asks for it
+/// explicitly; `Interval::All` starts at `f32`.
+const MIN_FLOAT_BITS: u16 = 16;
 
 /// Type variables can be used in place of concrete types when defining
 /// instructions. This makes the instructions *polymorphic*.
@@ -643,7 +646,7 @@ impl TypeSetBuilder {
             range_to_set(self.simd_lanes.to_range(min_lanes..MAX_LANES, Some(1))),
             range_to_set(self.dynamic_simd_lanes.to_range(2..MAX_LANES, None)),
             range_to_set(self.ints.to_range(8..MAX_BITS, None)),
-            range_to_set(self.floats.to_range(32..64, None)),
+            range_to_set(self.floats.to_range_with_min(32..64, MIN_FLOAT_BITS, None)),
             range_to_set(self.refs.to_range(32..64, None)),
         )
     }
@@ -658,6 +661,18 @@ pub(crate) enum Interval {
 
 impl Interval {
     fn to_range(&self, full_range: Range, default: Option<RangeBound>) -> Option<Range> {
+        let min = full_range.start;
+        self.to_range_with_min(full_range, min, default)
+    }
+
+    /// Like `to_range`, but an explicit range may extend down to `min`, below the start of the
+    /// range selected by `Interval::All`.
+    fn to_range_with_min(
+        &self,
+        full_range: Range,
+        min: RangeBound,
+        default: Option<RangeBound>,
+    ) -> Option<Range> {
         match self {
             Interval::None => default.map(|default_val| default_val..default_val),
 
@@ -668,7 +683,7 @@ impl Interval {
                 assert!(low.is_power_of_two());
                 assert!(high.is_power_of_two());
                 assert!(low <= high);
-                assert!(low >= full_range.start);
+                assert!(low >= min);
                 assert!(high <= full_range.end);
                 Some(low..high)
             }
@@ -714,6 +729,9 @@ fn test_typevar_builder() {
     assert_eq!(type_set.floats, num_set![32, 64]);
     assert!(type_set.ints.is_empty());
 
+    let type_set = TypeSetBuilder::new().floats(16..64).build();
+    assert_eq!(type_set.floats, num_set![16, 32, 64]);
+
     let type_set = TypeSetBuilder::new()
         .floats(Interval::All)
         .simd_lanes(Interval::All)
@@ -707,7 +707,7 @@ pub(crate) fn define(
         "Any type that can be stored in memory",
         TypeSetBuilder::new()
             .ints(Interval::All)
-            .floats(Interval::All)
+            .floats(16..64)
             .simd_lanes(Interval::All)
             .refs(Interval::All)
             .dynamic_simd_lanes(Interval::All)
@@ -2136,6 +2136,121 @@ pub(crate) fn define(
                 ])
                 .operands_out(vec![Operand::new("a", NarrowScalar), of_out.clone()]),
             );
+
+            ig.push(
+                Inst::new(
+                    "sadd_overflow_trap",
+                    r#"
+                Signed addition of x and y, trapping if the result overflows.
+
+                This is legalized into `sadd_overflow` followed by a `trapnz` on the overflow
+                flag.
+                Polymorphic over all scalar integer types, but does not support vector
+                types.
+                "#,
+                    &formats.int_add_trap,
+                )
+                .operands_in(vec![
+                    Operand::new("x", iB),
+                    Operand::new("y", iB),
+                    Operand::new("code", &imm.trapcode),
+                ])
+                .operands_out(vec![Operand::new("a", iB)])
+                .can_trap()
+                .side_effects_idempotent(),
+            );
+
+            ig.push(
+                Inst::new(
+                    "usub_overflow_trap",
+                    r#"
+                Unsigned subtraction of y from x, trapping if the result underflows.
+
+                This is legalized into `usub_overflow` followed by a `trapnz` on the overflow
+                flag.
+                Polymorphic over all scalar integer types, but does not support vector
+                types.
+                "#,
+                    &formats.int_add_trap,
+                )
+                .operands_in(vec![
+                    Operand::new("x", iB),
+                    Operand::new("y", iB),
+                    Operand::new("code", &imm.trapcode),
+                ])
+                .operands_out(vec![Operand::new("a", iB)])
+                .can_trap()
+                .side_effects_idempotent(),
+            );
+
+            ig.push(
+                Inst::new(
+                    "ssub_overflow_trap",
+                    r#"
+                Signed subtraction of y from x, trapping if the result over- or underflows.
+
+                This is legalized into `ssub_overflow` followed by a `trapnz` on the overflow
+                flag.
+                Polymorphic over all scalar integer types, but does not support vector
+                types.
+                "#,
+                    &formats.int_add_trap,
+                )
+                .operands_in(vec![
+                    Operand::new("x", iB),
+                    Operand::new("y", iB),
+                    Operand::new("code", &imm.trapcode),
+                ])
+                .operands_out(vec![Operand::new("a", iB)])
+                .can_trap()
+                .side_effects_idempotent(),
+            );
+
+            ig.push(
+                Inst::new(
+                    "umul_overflow_trap",
+                    r#"
+                Unsigned multiplication of x and y, trapping if the result overflows.
+
+                This is legalized into `umul_overflow` followed by a `trapnz` on the overflow
+                flag.
+                Polymorphic over all scalar integer types except i128, but does not support vector
+                types.
+                "#,
+                    &formats.int_add_trap,
+                )
+                .operands_in(vec![
+                    Operand::new("x", NarrowScalar),
+                    Operand::new("y", NarrowScalar),
+                    Operand::new("code", &imm.trapcode),
+                ])
+                .operands_out(vec![Operand::new("a", NarrowScalar)])
+                .can_trap()
+                .side_effects_idempotent(),
+            );
+
+            ig.push(
+                Inst::new(
+                    "smul_overflow_trap",
+                    r#"
+                Signed multiplication of x and y, trapping if the result over- or underflows.
+
+                This is legalized into `smul_overflow` followed by a `trapnz` on the overflow
+                flag.
+                Polymorphic over all scalar integer types except i128, but does not support vector
+                types.
+                "#,
+                    &formats.int_add_trap,
+                )
+                .operands_in(vec![
+                    Operand::new("x", NarrowScalar),
+                    Operand::new("y", NarrowScalar),
+                    Operand::new("code", &imm.trapcode),
+                ])
+                .operands_out(vec![Operand::new("a", NarrowScalar)])
+                .can_trap()
+                .side_effects_idempotent(),
+            );
         }
     }
 
@@ -3342,6 +3457,12 @@ pub(crate) fn define(
         TypeSetBuilder::new().floats(Interval::All).build(),
     );
 
+    let FloatFormat = &TypeVar::new(
+        "FloatFormat",
+        "A scalar floating point number in any format, including the storage-only `f16`",
+        TypeSetBuilder::new().floats(16..64).build(),
+    );
+
     ig.push(
         Inst::new(
             "fpromote",
@@ -3351,18 +3472,19 @@ pub(crate) fn define(
         Each lane in `x` is converted to the destination floating point format.
         This is an exact operation.
 
-        Cranelift currently only supports two floating point formats
-        - `f32` and `f64`. This may change in the future.
+        Cranelift supports the `f16`, `f32`, and `f64` floating point
+        formats. `f16` is a storage-only format, and converting it is the only
+        way to compute with it.
 
         The result type must have the same number of vector lanes as the input,
         and the result lanes must not have fewer bits than the input lanes.
         "#,
             &formats.unary,
         )
-        .operands_in(vec![Operand::new("x", &FloatScalar.narrower()).with_doc(
+        .operands_in(vec![Operand::new("x", &FloatFormat.narrower()).with_doc(
             "A scalar only floating point number, narrower than the controlling type",
         )])
-        .operands_out(vec![Operand::new("a", FloatScalar)]),
+        .operands_out(vec![Operand::new("a", FloatFormat)]),
     );
 
     ig.push(
@@ -3374,18 +3496,19 @@ pub(crate) fn define(
         Each lane in `x` is converted to the destination floating point format
         by rounding to nearest, ties to even.
 
-        Cranelift currently only supports two floating point formats
-        - `f32` and `f64`. This may change in the future.
+        Cranelift supports the `f16`, `f32`, and `f64` floating point
+        formats. `f16` is a storage-only format, and converting it is the only
+        way to compute with it.
 
         The result type must have the same number of vector lanes as the input,
         and the result lanes must not have more bits than the input lanes.
         "#,
             &formats.unary,
         )
-        .operands_in(vec![Operand::new("x", &FloatScalar.wider()).with_doc(
+        .operands_in(vec![Operand::new("x", &FloatFormat.wider()).with_doc(
             "A scalar only floating point number, wider than the controlling type",
         )])
-        .operands_out(vec![Operand::new("a", FloatScalar)]),
+        .operands_out(vec![Operand::new("a", FloatFormat)]),
     );
 
     let F64x2 = &TypeVar::new(
@@ -3762,6 +3885,25 @@ pub(crate) fn define(
         .other_side_effects(),
     );
 
+    ig.push(
+        Inst::new(
+            "compiler_fence",
+            r#"
+        A compiler-only memory fence.
+
+        Optimizations and instruction scheduling may not move loads or stores forwards or
+        backwards across this instruction, but it emits no machine code and so does not order
+        memory accesses as observed by other threads.
+
+        The exception is loads that are both `readonly` and `notrap`. The memory they read never
+        changes, so they are treated as pure values and may still be moved across this
+        instruction.
+        "#,
+            &formats.nullary,
+        )
+        .other_side_effects(),
+    );
+
     let TxN = &TypeVar::new(
         "TxN",
         "A dynamic vector type",
@@ -63,6 +63,18 @@ pub(crate) fn define() -> SettingGroup {
         true,
     );
 
+    settings.add_bool(
+        "reject_irreducible_control_flow",
+        "Make the verifier reject functions with irreducible control flow.",
+        r#"
+            Irreducible control flow, where a cycle can be entered at more than one block, is
+            valid IR, but loop analysis doesn't treat such cycles as loops and doesn't optimize
+            them. Front ends that only produce structured control flow, such as WebAssembly, can
+            enable this to have the verifier report any irreducible cycle as an error.
+        "#,
+        false,
+    );
+
     settings.add_bool(
         "enable_pcc",
         "Enable proof-carrying code translation validation.",
@@ -118,6 +130,39 @@ pub(crate) fn define() -> SettingGroup {
         false,
     );
 
+    settings.add_bool(
+        "enable_fma_contraction",
+        "Allow contracting floating-point multiplies and adds into fused multiply-adds.",
+        r#"
+            When enabled, the optimizer may rewrite an `fadd` or `fsub` of an `fmul` result into
+            a single `fma`, which rounds once instead of twice. This is faster on targets with a
+            native fused multiply-add, but the result can differ in the last bit from the
+            separately rounded operations, so it is not enabled by default.
+            Only effective when `opt_level` is `speed` or `speed_and_size`.
+        "#,
+        false,
+    );
+
+    settings.add_enum(
+        "float_mode",
+        "Floating-point semantics the optimizer must preserve.",
+        r#"
+            Supported modes:
+
+            - `strict`: Preserve IEEE 754 results bit for bit, including NaN
+              propagation and the sign of zero.
+            - `relaxed`: Allow rewrites that can change results, trading
+              reproducibility for speed: contracting multiplies and adds into
+              fused multiply-adds (as with `enable_fma_contraction`),
+              reassociating chains of `fadd` and `fmul`, and turning
+              compare-and-select idioms into `fmin`/`fmax` while ignoring NaNs
+              and the sign of zero.
+
+            Only effective when `opt_level` is `speed` or `speed_and_size`.
+        "#,
+        vec!["strict", "relaxed"],
+    );
+
     settings.add_bool(
         "enable_pinned_reg",
         "Enable the use of the pinned register.",
@@ -342,6 +387,21 @@ pub(crate) fn define() -> SettingGroup {
         0,
     );
 
+    settings.add_num(
+        "max_insts_log2",
+        "The log2 of the maximum number of CLIF instructions a function may grow to.",
+        r#"
+            Legalization and optimization can expand a function's instructions.
+            If a function has more than two raised to the power of this value
+            instructions before or after those passes, compilation fails with
+            `CodegenError::ImplLimitExceeded` instead of continuing. This bounds
+            the work spent on pathological inputs.
+
+            The default is 0, which means there is no limit.
+        "#,
+        0,
+    );
+
     // When adding new settings please check if they can also be added
     // in cranelift/fuzzgen/src/lib.rs for fuzzing.
     settings.build()
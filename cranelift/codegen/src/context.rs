@@ -10,10 +10,12 @@
 //! single ISA instance.
 
 use crate::alias_analysis::AliasAnalysis;
+use crate::coverage::{do_coverage_instrumentation, CoverageMap};
+use crate::cursor::{Cursor, FuncCursor};
 use crate::dominator_tree::DominatorTree;
 use crate::egraph::EgraphPass;
 use crate::flowgraph::ControlFlowGraph;
-use crate::ir::Function;
+use crate::ir::{self, Function, InstBuilder};
 use crate::isa::TargetIsa;
 use crate::legalizer::simple_legalize;
 use crate::loop_analysis::LoopAnalysis;
@@ -24,8 +26,8 @@ use crate::result::{CodegenResult, CompileResult};
 use crate::settings::{FlagsOrIsa, OptLevel};
 use crate::trace;
 use crate::unreachable_code::eliminate_unreachable_code;
-use crate::verifier::{verify_context, VerifierErrors, VerifierResult};
-use crate::{timing, CompileError};
+use crate::verifier::{validate_quick, verify_context, VerifierErrors, VerifierResult};
+use crate::{timing, CodegenError, CompileError};
 #[cfg(feature = "souper-harvest")]
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -54,6 +56,14 @@ pub struct Context {
 
     /// Flag: do we want a disassembly with the CompiledCode?
     pub want_disasm: bool,
+
+    /// Upper bound in bytes on `memory_usage()` during compilation, if any.
+    ///
+    /// Like `memory_usage()`, this only covers the IR and its analyses, not the memory used by
+    /// lowering, register allocation or the emitted code.
+    ///
+    /// Unlike the other settings, this is kept across calls to `clear()`.
+    pub memory_limit: Option<usize>,
 }
 
 impl Context {
@@ -77,6 +87,7 @@ impl Context {
             loop_analysis: LoopAnalysis::new(),
             compiled_code: None,
             want_disasm: false,
+            memory_limit: None,
         }
     }
 
@@ -102,6 +113,61 @@ impl Context {
         self.want_disasm = val;
     }
 
+    /// Limit the memory that compilation may use for the function's IR and analyses.
+    ///
+    /// When `memory_usage()` exceeds `limit` between passes, compilation fails with
+    /// `CodegenError::MemoryLimitExceeded`. `None` removes the limit.
+    ///
+    /// The limit is checked against an estimate of the IR and its analyses only. Memory used by
+    /// the backend for lowering, register allocation and the emitted code isn't counted, so the
+    /// peak usage of a compilation can be well above `limit`.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit;
+    }
+
+    /// Approximate number of heap bytes currently reserved by this context for the function's
+    /// IR, control flow graph, dominator tree and loop analysis.
+    ///
+    /// Transient allocations of individual passes and everything owned by the backend aren't
+    /// included.
+    pub fn memory_usage(&self) -> usize {
+        self.func.memory_usage()
+            + self.cfg.memory_usage()
+            + self.domtree.memory_usage()
+            + self.loop_analysis.memory_usage()
+    }
+
+    /// Fail with `CodegenError::ImplLimitExceeded` if the function has more instructions than the
+    /// `max_insts_log2` setting allows.
+    fn check_inst_limit(&self, isa: &dyn TargetIsa) -> CodegenResult<()> {
+        let log2 = isa.flags().max_insts_log2();
+        if log2 == 0 {
+            return Ok(());
+        }
+        let limit = 1usize.checked_shl(u32::from(log2)).unwrap_or(usize::MAX);
+        let num_insts = self.func.dfg.num_insts();
+        if num_insts > limit {
+            log::debug!("instruction limit exceeded: {} > {}", num_insts, limit);
+            return Err(CodegenError::ImplLimitExceeded);
+        }
+        Ok(())
+    }
+
+    /// Fail with `CodegenError::MemoryLimitExceeded` if the memory limit has been exceeded.
+    fn check_memory_limit(&self) -> CodegenResult<()> {
+        match self.memory_limit {
+            Some(limit) if self.memory_usage() > limit => {
+                log::debug!(
+                    "memory limit exceeded: {} > {} bytes",
+                    self.memory_usage(),
+                    limit
+                );
+                Err(CodegenError::MemoryLimitExceeded)
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Compile the function, and emit machine code into a `Vec<u8>`.
     ///
     /// Run the function through all the passes necessary to generate
@@ -137,11 +203,31 @@ impl Context {
         isa: &dyn TargetIsa,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<CompiledCodeStencil> {
+        #[cfg(feature = "tracing-spans")]
+        let _span = tracing::info_span!(
+            "compile",
+            function = %self.func.name,
+            blocks = self.func.dfg.num_blocks(),
+            insts = self.func.dfg.num_insts(),
+            values = self.func.dfg.num_values(),
+        )
+        .entered();
         let _tt = timing::compile();
 
+        log::debug!(
+            "Compiling function {} ({} blocks, {} insts, {} values)",
+            self.func.name,
+            self.func.dfg.num_blocks(),
+            self.func.dfg.num_insts(),
+            self.func.dfg.num_values()
+        );
+
         self.verify_if(isa)?;
 
         self.optimize(isa, ctrl_plane)?;
+        self.check_memory_limit()?;
+
+        crate::trace!("Optimized function:\n{}", self.func.display());
 
         isa.compile_function(&self.func, &self.domtree, self.want_disasm, ctrl_plane)
     }
@@ -172,12 +258,17 @@ impl Context {
             self.func.display()
         );
 
+        self.fill_empty_body();
+        self.check_inst_limit(isa)?;
         self.compute_cfg();
+        self.check_memory_limit()?;
         if isa.flags().enable_nan_canonicalization() {
             self.canonicalize_nans(isa)?;
         }
 
         self.legalize(isa)?;
+        self.check_inst_limit(isa)?;
+        self.check_memory_limit()?;
 
         self.compute_domtree();
         self.eliminate_unreachable_code(isa)?;
@@ -187,6 +278,7 @@ impl Context {
 
         if opt_level != OptLevel::None {
             self.egraph_pass(isa, ctrl_plane)?;
+            self.check_inst_limit(isa)?;
         }
 
         Ok(())
@@ -251,6 +343,13 @@ impl Context {
         }
     }
 
+    /// Run the quick structural validator on the function.
+    ///
+    /// See [`validate_quick`] for what is and isn't checked.
+    pub fn validate_quick<'a, FOI: Into<FlagsOrIsa<'a>>>(&self, fisa: FOI) -> VerifierResult<()> {
+        validate_quick(&self.func, fisa)
+    }
+
     /// Run the verifier only if the `enable_verifier` setting is true.
     pub fn verify_if<'a, FOI: Into<FlagsOrIsa<'a>>>(&self, fisa: FOI) -> CodegenResult<()> {
         let fisa = fisa.into();
@@ -270,6 +369,30 @@ impl Context {
         Ok(())
     }
 
+    /// Give a function without any blocks an entry block that traps.
+    ///
+    /// A function with no body is valid IR, but there is no code in it that could run or return,
+    /// so it's compiled as if its body were a single `trap unreachable`.
+    fn fill_empty_body(&mut self) {
+        if self.func.layout.entry_block().is_some() {
+            return;
+        }
+        let block = self.func.dfg.make_block();
+        let param_types: Vec<_> = self
+            .func
+            .signature
+            .params
+            .iter()
+            .map(|p| p.value_type)
+            .collect();
+        for ty in param_types {
+            self.func.dfg.append_block_param(block, ty);
+        }
+        let mut pos = FuncCursor::new(&mut self.func);
+        pos.insert_block(block);
+        pos.ins().trap(ir::TrapCode::UnreachableCodeReached);
+    }
+
     /// Perform NaN canonicalizing rewrites on the function.
     pub fn canonicalize_nans(&mut self, isa: &dyn TargetIsa) -> CodegenResult<()> {
         // Currently only RiscV64 is the only arch that may not have vector support.
@@ -284,6 +407,20 @@ impl Context {
         self.verify_if(isa)
     }
 
+    /// Insert block-level coverage counters into the function.
+    ///
+    /// `counters` is a pointer-typed global value holding the base address
+    /// of the counter table. Returns the layout of that table.
+    pub fn instrument_coverage(
+        &mut self,
+        isa: &dyn TargetIsa,
+        counters: ir::GlobalValue,
+    ) -> CodegenResult<CoverageMap> {
+        let map = do_coverage_instrumentation(&mut self.func, counters, isa.pointer_type());
+        self.verify_if(isa)?;
+        Ok(map)
+    }
+
     /// Run the legalizer for `isa` on the function.
     pub fn legalize(&mut self, isa: &dyn TargetIsa) -> CodegenResult<()> {
         // Legalization invalidates the domtree and loop_analysis by mutating the CFG.
@@ -292,7 +429,7 @@ impl Context {
         self.loop_analysis.clear();
 
         // Run some specific legalizations only.
-        simple_legalize(&mut self.func, &mut self.cfg, isa);
+        simple_legalize(&mut self.func, &mut self.cfg, isa)?;
         self.verify_if(isa)
     }
 
@@ -372,6 +509,7 @@ impl Context {
             &self.loop_analysis,
             &mut alias_analysis,
             &fisa.flags,
+            fisa.isa,
             ctrl_plane,
         );
         pass.run();
@@ -382,3 +520,133 @@ impl Context {
         self.verify_if(fisa)
     }
 }
+
+#[cfg(all(test, feature = "x86"))]
+mod tests {
+    use super::*;
+    use crate::ir::{types, AbiParam, Signature};
+    use crate::isa::{lookup, CallConv, OwnedTargetIsa};
+    use crate::settings::{builder, Flags};
+    use target_lexicon::triple;
+
+    fn isa() -> OwnedTargetIsa {
+        lookup(triple!("x86_64"))
+            .expect("expect x86 ISA")
+            .finish(Flags::new(builder()))
+            .expect("expect backend creation to succeed")
+    }
+
+    /// A function that adds up a chain of constants.
+    fn create_function() -> Function {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.returns.push(AbiParam::new(types::I64));
+        let mut func = Function::with_name_signature(Default::default(), sig);
+
+        let block0 = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        let mut sum = pos.ins().iconst(types::I64, 0);
+        for i in 1..100 {
+            let c = pos.ins().iconst(types::I64, i);
+            sum = pos.ins().iadd(sum, c);
+        }
+        pos.ins().return_(&[sum]);
+        func
+    }
+
+    #[test]
+    fn memory_limit_exceeded() {
+        let mut context = Context::for_function(create_function());
+        context.set_memory_limit(Some(64));
+        let err = context
+            .compile(&*isa(), &mut Default::default())
+            .expect_err("expected the memory limit to be exceeded");
+        assert!(
+            matches!(err.inner, CodegenError::MemoryLimitExceeded),
+            "{:?}",
+            err.inner
+        );
+    }
+
+    #[test]
+    fn memory_limit_not_exceeded() {
+        let mut context = Context::for_function(create_function());
+        let usage = context.memory_usage();
+        assert!(usage > 64, "{usage}");
+        context.set_memory_limit(Some(64 << 20));
+        context
+            .compile(&*isa(), &mut Default::default())
+            .expect("expected compilation");
+
+        // The limit is kept for the next function.
+        context.clear();
+        assert_eq!(context.memory_limit, Some(64 << 20));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing-spans")]
+    fn tracing_spans() {
+        use alloc::string::{String, ToString};
+        use alloc::sync::Arc;
+        use core::fmt::Write;
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Formats the fields of a span after its name.
+        struct Fields(String);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                write!(self.0, " {}={:?}", field.name(), value).unwrap();
+            }
+        }
+
+        /// Records every span that is created.
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes) -> Id {
+                let mut fields = Fields(span.metadata().name().to_string());
+                span.record(&mut fields);
+                let mut spans = self.0.lock().unwrap();
+                spans.push(fields.0);
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(Recorder(spans.clone()), || {
+            Context::for_function(create_function())
+                .compile(&*isa(), &mut Default::default())
+                .expect("expected compilation");
+        });
+
+        let spans = spans.lock().unwrap();
+        assert_eq!(
+            spans[0], "compile function=u0:0 blocks=1 insts=200 values=199",
+            "{spans:?}"
+        );
+        for pass in [
+            "compile",
+            "legalize",
+            "vcode_lower",
+            "regalloc",
+            "vcode_emit",
+        ] {
+            assert!(
+                spans.contains(&format!("pass pass={pass}")),
+                "no span for {pass}: {spans:?}"
+            );
+        }
+    }
+}
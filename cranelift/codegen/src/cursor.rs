@@ -596,6 +596,17 @@ impl<'f> FuncCursor<'f> {
     pub fn ins(&mut self) -> ir::InsertBuilder<'_, &mut FuncCursor<'f>> {
         ir::InsertBuilder::new(self)
     }
+
+    /// Create an instruction builder that replaces the instruction under the cursor.
+    ///
+    /// The replaced instruction keeps its result values and its place in the layout, so the
+    /// cursor position is unchanged.
+    ///
+    /// Panics if the cursor is not pointing at an instruction.
+    pub fn replace(&mut self) -> ir::ReplaceBuilder<'_> {
+        let inst = self.current_inst().expect("No instruction to replace");
+        self.func.dfg.replace(inst)
+    }
 }
 
 impl<'f> Cursor for FuncCursor<'f> {
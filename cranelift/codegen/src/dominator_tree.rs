@@ -261,6 +261,13 @@ impl DominatorTree {
         self.valid = false;
     }
 
+    /// Approximate number of heap bytes reserved by the dominator tree.
+    pub fn memory_usage(&self) -> usize {
+        self.nodes.capacity() * mem::size_of::<DomNode>()
+            + self.postorder.capacity() * mem::size_of::<Block>()
+            + self.stack.capacity() * mem::size_of::<(Visit, Block)>()
+    }
+
     /// Check if the dominator tree is in a valid state.
     ///
     /// Note that this doesn't perform any kind of validity checks. It simply checks if the
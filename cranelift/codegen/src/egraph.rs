@@ -11,6 +11,7 @@ use crate::ir::{
     Block, DataFlowGraph, Function, Inst, InstructionData, Opcode, Type, Value, ValueDef,
     ValueListPool,
 };
+use crate::isa::TargetIsa;
 use crate::loop_analysis::LoopAnalysis;
 use crate::opts::IsleContext;
 use crate::scoped_hash_map::{Entry as ScopedEntry, ScopedHashMap};
@@ -60,6 +61,8 @@ pub struct EgraphPass<'a> {
     loop_analysis: &'a LoopAnalysis,
     /// Compiler flags.
     flags: &'a Flags,
+    /// Target ISA, if known, for rewrites that only pay off with some target features.
+    isa: Option<&'a dyn TargetIsa>,
     /// Chaos-mode control-plane so we can test that we still get
     /// correct results when our heuristics make bad decisions.
     ctrl_plane: &'a mut ControlPlane,
@@ -96,7 +99,8 @@ where
     domtree: &'opt DominatorTreePreorder,
     pub(crate) alias_analysis: &'opt mut AliasAnalysis<'analysis>,
     pub(crate) alias_analysis_state: &'opt mut LastStores,
-    flags: &'opt Flags,
+    pub(crate) flags: &'opt Flags,
+    pub(crate) isa: Option<&'opt dyn TargetIsa>,
     ctrl_plane: &'opt mut ControlPlane,
     // Held locally during optimization of one node (recursively):
     pub(crate) rewrite_depth: usize,
@@ -508,6 +512,7 @@ impl<'a> EgraphPass<'a> {
         loop_analysis: &'a LoopAnalysis,
         alias_analysis: &'a mut AliasAnalysis<'a>,
         flags: &'a Flags,
+        isa: Option<&'a dyn TargetIsa>,
         ctrl_plane: &'a mut ControlPlane,
     ) -> Self {
         let num_values = func.dfg.num_values();
@@ -519,6 +524,7 @@ impl<'a> EgraphPass<'a> {
             loop_analysis,
             alias_analysis,
             flags,
+            isa,
             ctrl_plane,
             stats: Stats::default(),
             eclasses: UnionFind::with_capacity(num_values),
@@ -689,6 +695,7 @@ impl<'a> EgraphPass<'a> {
                             alias_analysis: self.alias_analysis,
                             alias_analysis_state: &mut alias_analysis_state,
                             flags: self.flags,
+                            isa: self.isa,
                             ctrl_plane: self.ctrl_plane,
                             optimized_values: Default::default(),
                         };
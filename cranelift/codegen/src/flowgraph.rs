@@ -94,6 +94,13 @@ impl ControlFlowGraph {
         self.valid = false;
     }
 
+    /// Approximate number of heap bytes reserved by the per-block CFG nodes.
+    ///
+    /// The predecessor and successor B-forests are not included.
+    pub fn memory_usage(&self) -> usize {
+        self.data.capacity() * mem::size_of::<CFGNode>()
+    }
+
     /// Allocate and compute the control flow graph for `func`.
     pub fn with_function(func: &Function) -> Self {
         let mut cfg = Self::new();
@@ -171,6 +171,7 @@ pub fn has_memory_fence_semantics(op: Opcode) -> bool {
         | Opcode::AtomicLoad
         | Opcode::AtomicStore
         | Opcode::Fence
+        | Opcode::CompilerFence
         | Opcode::Debugtrap => true,
         Opcode::Call | Opcode::CallIndirect => true,
         op if op.can_trap() => true,
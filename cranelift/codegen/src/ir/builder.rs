@@ -264,6 +264,25 @@ mod tests {
         assert_eq!(pos.func.dfg.value_def(v0), ValueDef::Result(iconst, 0));
     }
 
+    #[test]
+    fn replace_at_cursor() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        let arg0 = func.dfg.append_block_param(block0, I32);
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+
+        let v0 = pos.ins().iadd_imm(arg0, 17);
+        let iadd = pos.prev_inst().unwrap();
+
+        // Turn the `iadd_imm` into an `iconst` without touching its result value.
+        let v0b = pos.replace().iconst(I32, 3);
+        assert_eq!(v0, v0b);
+        assert_eq!(pos.current_inst(), Some(iadd));
+        assert_eq!(pos.func.dfg.value_def(v0), ValueDef::Result(iadd, 0));
+        assert_eq!(pos.func.dfg.insts[iadd].opcode(), crate::ir::Opcode::Iconst);
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
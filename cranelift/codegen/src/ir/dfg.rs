@@ -4,11 +4,12 @@ use crate::entity::{self, PrimaryMap, SecondaryMap};
 use crate::ir;
 use crate::ir::builder::ReplaceBuilder;
 use crate::ir::dynamic_type::{DynamicTypeData, DynamicTypes};
+use crate::ir::immediates::{Ieee32, Ieee64, Imm64};
 use crate::ir::instructions::{CallInfo, InstructionData};
 use crate::ir::pcc::Fact;
 use crate::ir::{
-    types, Block, BlockCall, ConstantData, ConstantPool, DynamicType, ExtFuncData, FuncRef,
-    Immediate, Inst, JumpTables, RelSourceLoc, SigRef, Signature, Type, Value,
+    types, Block, BlockCall, Constant, ConstantData, ConstantPool, DynamicType, ExtFuncData,
+    FuncRef, Immediate, Inst, JumpTables, Opcode, RelSourceLoc, SigRef, Signature, Type, Value,
     ValueLabelAssignments, ValueList, ValueListPool,
 };
 use crate::packed_option::ReservedValue;
@@ -20,6 +21,7 @@ use core::ops::{Index, IndexMut};
 use core::u16;
 
 use alloc::collections::BTreeMap;
+use alloc::string::String;
 #[cfg(feature = "enable-serde")]
 use serde_derive::{Deserialize, Serialize};
 use smallvec::SmallVec;
@@ -139,6 +141,10 @@ pub struct DataFlowGraph {
     /// Saves Value labels.
     pub values_labels: Option<BTreeMap<Value, ValueLabelAssignments>>,
 
+    /// Optional human-readable names for values, e.g. the source variable a value was computed
+    /// for. They have no semantic meaning and are only shown as comments when printing.
+    value_names: BTreeMap<Value, String>,
+
     /// Constants used within the function.
     pub constants: ConstantPool,
 
@@ -163,6 +169,7 @@ impl DataFlowGraph {
             signatures: PrimaryMap::new(),
             ext_funcs: PrimaryMap::new(),
             values_labels: None,
+            value_names: BTreeMap::new(),
             constants: ConstantPool::new(),
             immediates: PrimaryMap::new(),
             jump_tables: JumpTables::new(),
@@ -180,12 +187,26 @@ impl DataFlowGraph {
         self.signatures.clear();
         self.ext_funcs.clear();
         self.values_labels = None;
+        self.value_names.clear();
         self.constants.clear();
         self.immediates.clear();
         self.jump_tables.clear();
         self.facts.clear();
     }
 
+    /// Approximate number of heap bytes reserved by the instruction, block and value tables.
+    ///
+    /// This counts allocated capacity rather than live entries, so it reflects what the
+    /// data-flow graph actually holds on to.
+    pub fn memory_usage(&self) -> usize {
+        self.insts.0.capacity() * mem::size_of::<InstructionData>()
+            + self.results.capacity() * mem::size_of::<ValueList>()
+            + self.blocks.0.capacity() * mem::size_of::<BlockData>()
+            + self.value_lists.capacity() * mem::size_of::<Value>()
+            + self.values.capacity() * mem::size_of::<ValueDataPacked>()
+            + self.facts.capacity() * mem::size_of::<Option<Fact>>()
+    }
+
     /// Get the total number of instructions created in this function, whether they are currently
     /// inserted in the layout or not.
     ///
@@ -241,6 +262,20 @@ impl DataFlowGraph {
             values_labels.insert(to_alias, ir::ValueLabelAssignments::Alias { from, value });
         }
     }
+
+    /// Attach a human-readable name to `v`, replacing any previous name.
+    ///
+    /// Names are kept on a best-effort basis: turning a named value into an alias moves its name
+    /// to the aliased value if that doesn't have one yet, but passes are otherwise free to drop
+    /// them.
+    pub fn set_value_name(&mut self, v: Value, name: impl Into<String>) {
+        self.value_names.insert(v, name.into());
+    }
+
+    /// Get the human-readable name attached to `v`, if any.
+    pub fn value_name(&self, v: Value) -> Option<&str> {
+        self.value_names.get(&v).map(|name| name.as_str())
+    }
 }
 
 /// Resolve value aliases.
@@ -336,6 +371,9 @@ impl DataFlowGraph {
     }
 
     /// Get the type of a value.
+    ///
+    /// Unlike `value_def`, this is also valid for detached values, which keep their type so that
+    /// they can be redefined, e.g. with `InstBuilder::with_result`.
     pub fn value_type(&self, v: Value) -> Type {
         self.values[v].ty()
     }
@@ -344,7 +382,12 @@ impl DataFlowGraph {
     ///
     /// This is either the instruction that defined it or the Block that has the value as an
     /// parameter.
+    ///
+    /// With the `dfg-checks` feature, this panics if `v` has been detached from its instruction
+    /// or block, since the definition recorded for it is stale.
+    #[track_caller]
     pub fn value_def(&self, v: Value) -> ValueDef {
+        self.check_not_detached(v);
         match ValueData::from(self.values[v]) {
             ValueData::Inst { inst, num, .. } => ValueDef::Result(inst, num as usize),
             ValueData::Param { block, num, .. } => ValueDef::Param(block, num as usize),
@@ -357,6 +400,25 @@ impl DataFlowGraph {
         }
     }
 
+    /// Panic if `v` is a detached instruction result or block parameter and the `dfg-checks`
+    /// feature is enabled.
+    ///
+    /// Values are detached by `clear_results`, `replace_result`, `detach_block_params`,
+    /// `replace_block_param` and the block parameter removal methods. A detached value still
+    /// records where it used to be defined, so reading its definition silently returns stale
+    /// data until it is reattached or turned into an alias.
+    #[inline]
+    #[track_caller]
+    fn check_not_detached(&self, v: Value) {
+        if cfg!(feature = "dfg-checks") {
+            let attached = match ValueData::from(self.values[v]) {
+                ValueData::Inst { .. } | ValueData::Param { .. } => self.value_is_attached(v),
+                ValueData::Alias { .. } | ValueData::Union { .. } => true,
+            };
+            assert!(attached, "use of detached value {v}");
+        }
+    }
+
     /// Determine if `v` is an attached instruction result / block parameter.
     ///
     /// An attached value can't be attached to something else without first being detached.
@@ -373,11 +435,71 @@ impl DataFlowGraph {
         }
     }
 
+    /// If `v` is the result of an `iconst`, get its immediate.
+    ///
+    /// Aliases are resolved first. Values narrower than 64 bits are zero-extended in the
+    /// immediate, as the verifier requires.
+    pub fn value_as_iconst(&self, v: Value) -> Option<Imm64> {
+        match self.value_inst_data(v)? {
+            InstructionData::UnaryImm {
+                opcode: Opcode::Iconst,
+                imm,
+            } => Some(imm),
+            _ => None,
+        }
+    }
+
+    /// If `v` is the result of an `f32const`, get its immediate.
+    pub fn value_as_f32const(&self, v: Value) -> Option<Ieee32> {
+        match self.value_inst_data(v)? {
+            InstructionData::UnaryIeee32 {
+                opcode: Opcode::F32const,
+                imm,
+            } => Some(imm),
+            _ => None,
+        }
+    }
+
+    /// If `v` is the result of an `f64const`, get its immediate.
+    pub fn value_as_f64const(&self, v: Value) -> Option<Ieee64> {
+        match self.value_inst_data(v)? {
+            InstructionData::UnaryIeee64 {
+                opcode: Opcode::F64const,
+                imm,
+            } => Some(imm),
+            _ => None,
+        }
+    }
+
+    /// If `v` is the result of a `vconst`, get the handle of its data in the constant pool.
+    pub fn value_as_vconst(&self, v: Value) -> Option<Constant> {
+        match self.value_inst_data(v)? {
+            InstructionData::UnaryConst {
+                opcode: Opcode::Vconst,
+                constant_handle,
+            } => Some(constant_handle),
+            _ => None,
+        }
+    }
+
+    /// Get the instruction data defining `v`, if it is an instruction result.
+    fn value_inst_data(&self, v: Value) -> Option<InstructionData> {
+        let inst = self.value_def(self.resolve_aliases(v)).inst()?;
+        Some(self.insts[inst])
+    }
+
     /// Resolve value aliases.
     ///
     /// Find the original SSA value that `value` aliases.
+    ///
+    /// With the `dfg-checks` feature, this panics if the original value has been detached from
+    /// its instruction or block. This also covers `change_to_alias` and the `value_as_*`
+    /// accessors.
+    #[track_caller]
     pub fn resolve_aliases(&self, value: Value) -> Value {
-        resolve_aliases(&self.values, value)
+        let original = resolve_aliases(&self.values, value);
+        self.check_not_detached(original);
+        original
     }
 
     /// Replace all uses of value aliases with their resolved values, and delete
@@ -490,7 +612,9 @@ impl DataFlowGraph {
     /// Change the `dest` value to behave as an alias of `src`. This means that all uses of `dest`
     /// will behave as if they used that value `src`.
     ///
-    /// The `dest` value can't be attached to an instruction or block.
+    /// The `dest` value can't be attached to an instruction or block. With the `dfg-checks`
+    /// feature, this panics if `src` resolves to a detached value.
+    #[track_caller]
     pub fn change_to_alias(&mut self, dest: Value, src: Value) {
         debug_assert!(!self.value_is_attached(dest));
         // Try to create short alias chains by finding the original source value.
@@ -514,6 +638,13 @@ impl DataFlowGraph {
         debug_assert_ne!(ty, types::INVALID);
 
         self.values[dest] = ValueData::Alias { ty, original }.into();
+
+        if let Some(name) = self.value_names.get(&dest) {
+            if !self.value_names.contains_key(&original) {
+                let name = name.clone();
+                self.value_names.insert(original, name);
+            }
+        }
     }
 
     /// Replace the results of one instruction with aliases to the results of another.
@@ -1571,7 +1702,7 @@ impl DataFlowGraph {
 mod tests {
     use super::*;
     use crate::cursor::{Cursor, FuncCursor};
-    use crate::ir::{Function, Opcode, TrapCode};
+    use crate::ir::{Function, InstBuilder, Opcode, TrapCode};
     use alloc::string::ToString;
 
     #[test]
@@ -1612,6 +1743,82 @@ mod tests {
         assert_eq!(dfg.value_type(v2), types::F64);
     }
 
+    #[test]
+    fn value_as_const() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        let block1 = func.dfg.make_block();
+        let v5 = func.dfg.append_block_param(block1, types::I8);
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        let v0 = pos.ins().iconst(types::I8, 0xff);
+        let v1 = pos.ins().f32const(Ieee32::with_float(1.5));
+        let v2 = pos.ins().f64const(Ieee64::with_float(-0.0));
+        let handle = pos.func.dfg.constants.insert(vec![0; 16].into());
+        let v3 = pos.ins().vconst(types::I8X16, handle);
+        let v4 = pos.ins().iadd(v0, v0);
+        pos.func.dfg.detach_block_params(block1);
+        pos.func.dfg.change_to_alias(v5, v0);
+
+        let dfg = &pos.func.dfg;
+        assert_eq!(dfg.value_as_iconst(v0), Some(Imm64::new(0xff)));
+        assert_eq!(dfg.value_as_iconst(v5), Some(Imm64::new(0xff)));
+        assert_eq!(dfg.value_as_iconst(v1), None);
+        assert_eq!(dfg.value_as_iconst(v4), None);
+        assert_eq!(dfg.value_as_f32const(v1), Some(Ieee32::with_float(1.5)));
+        assert_eq!(dfg.value_as_f64const(v2), Some(Ieee64::with_float(-0.0)));
+        assert_eq!(dfg.value_as_f64const(v1), None);
+        assert_eq!(dfg.value_as_vconst(v3), Some(handle));
+    }
+
+    #[test]
+    #[cfg(feature = "dfg-checks")]
+    #[should_panic(expected = "use of detached value v0")]
+    fn detached_value_def() {
+        let mut dfg = DataFlowGraph::new();
+
+        let idata = InstructionData::UnaryImm {
+            opcode: Opcode::Iconst,
+            imm: 0.into(),
+        };
+        let inst = dfg.make_inst(idata);
+        dfg.make_inst_results(inst, types::I32);
+        let val = dfg.first_result(inst);
+
+        dfg.clear_results(inst);
+        dfg.value_def(val);
+    }
+
+    #[test]
+    #[cfg(feature = "dfg-checks")]
+    #[should_panic(expected = "use of detached value v0")]
+    fn detached_alias_original() {
+        let mut dfg = DataFlowGraph::new();
+
+        let block = dfg.make_block();
+        let arg = dfg.append_block_param(block, types::I32);
+        let alias = dfg.make_value(ValueData::Alias {
+            ty: types::I32,
+            original: arg,
+        });
+        dfg.detach_block_params(block);
+        dfg.resolve_aliases(alias);
+    }
+
+    #[test]
+    #[cfg(feature = "dfg-checks")]
+    #[should_panic(expected = "use of detached value v0")]
+    fn detached_alias_source() {
+        let mut dfg = DataFlowGraph::new();
+
+        let block = dfg.make_block();
+        let arg = dfg.append_block_param(block, types::I32);
+        dfg.detach_block_params(block);
+        let dest = dfg.append_block_param(block, types::I32);
+        dfg.detach_block_params(block);
+        dfg.change_to_alias(dest, arg);
+    }
+
     #[test]
     fn no_results() {
         let mut dfg = DataFlowGraph::new();
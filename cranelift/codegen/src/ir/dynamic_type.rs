@@ -4,6 +4,7 @@ use crate::ir::entities::DynamicType;
 use crate::ir::types::*;
 use crate::ir::GlobalValue;
 use crate::ir::PrimaryMap;
+use core::fmt;
 
 #[cfg(feature = "enable-serde")]
 use serde_derive::{Deserialize, Serialize};
@@ -34,6 +35,12 @@ impl DynamicTypeData {
     }
 }
 
+impl fmt::Display for DynamicTypeData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}*{}", self.base_vector_ty, self.dynamic_scale)
+    }
+}
+
 /// All allocated dynamic types.
 pub type DynamicTypes = PrimaryMap<DynamicType, DynamicTypeData>;
 
@@ -7,15 +7,17 @@ use crate::entity::{PrimaryMap, SecondaryMap};
 use crate::ir::{
     self, pcc::Fact, Block, DataFlowGraph, DynamicStackSlot, DynamicStackSlotData,
     DynamicStackSlots, DynamicType, ExtFuncData, FuncRef, GlobalValue, GlobalValueData, Inst,
-    JumpTable, JumpTableData, Layout, MemoryType, MemoryTypeData, Opcode, SigRef, Signature,
-    SourceLocs, StackSlot, StackSlotData, StackSlots, Type,
+    InstructionData, JumpTable, JumpTableData, Layout, MemoryType, MemoryTypeData, Opcode, SigRef,
+    Signature, SourceLocs, StackSlot, StackSlotData, StackSlots, Type, Value,
 };
 use crate::isa::CallConv;
 use crate::write::write_function;
 use crate::HashMap;
 #[cfg(feature = "enable-serde")]
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
+use core::mem;
 
 #[cfg(feature = "enable-serde")]
 use serde::de::{Deserializer, Error};
@@ -212,6 +214,16 @@ impl FunctionStencil {
         self.stack_limit = None;
     }
 
+    /// Approximate number of heap bytes reserved by the function's IR.
+    ///
+    /// This covers the data-flow graph, the layout and the source location table, which
+    /// together account for nearly all of the memory a function body uses.
+    pub fn memory_usage(&self) -> usize {
+        self.dfg.memory_usage()
+            + self.layout.memory_usage()
+            + self.srclocs.capacity() * mem::size_of::<RelSourceLoc>()
+    }
+
     /// Creates a jump table in the function, to be used by `br_table` instructions.
     pub fn create_jump_table(&mut self, data: JumpTableData) -> JumpTable {
         self.dfg.jump_tables.push(data)
@@ -274,6 +286,90 @@ impl FunctionStencil {
             .map(|i| self.dfg.block_params(entry)[i])
     }
 
+    /// Replace the signature of this function, keeping the entry block in sync with it.
+    ///
+    /// Every `return`, `return_call` and `return_call_indirect` instruction is first checked
+    /// against the return types of `signature`. If one doesn't match, the function is left
+    /// unchanged and the first invalid instruction is returned with an error message.
+    ///
+    /// Otherwise the entry block parameters are updated to match the new parameters: a parameter
+    /// is kept if its type is unchanged, replaced with a new value if its type changed, and
+    /// removed if the new signature has fewer parameters. Missing parameters are appended.
+    ///
+    /// Returns the entry block parameters that were replaced or removed. They are left detached,
+    /// so any remaining uses must be rewritten by the caller.
+    pub fn update_signature(
+        &mut self,
+        signature: Signature,
+    ) -> Result<Vec<Value>, (Inst, &'static str)> {
+        let expected_returns = || signature.returns.iter().map(|ret| ret.value_type);
+        for block in self.layout.blocks() {
+            let inst = match self.layout.last_inst(block) {
+                Some(inst) => inst,
+                None => continue,
+            };
+            let returns_match = match self.dfg.insts[inst] {
+                InstructionData::MultiAry {
+                    opcode: Opcode::Return,
+                    args,
+                } => args
+                    .as_slice(&self.dfg.value_lists)
+                    .iter()
+                    .map(|&arg| self.dfg.value_type(arg))
+                    .eq(expected_returns()),
+                InstructionData::Call {
+                    opcode: Opcode::ReturnCall,
+                    func_ref,
+                    ..
+                } => {
+                    let sig_ref = self.dfg.ext_funcs[func_ref].signature;
+                    self.dfg.signatures[sig_ref]
+                        .returns
+                        .iter()
+                        .map(|ret| ret.value_type)
+                        .eq(expected_returns())
+                }
+                InstructionData::CallIndirect {
+                    opcode: Opcode::ReturnCallIndirect,
+                    sig_ref,
+                    ..
+                } => self.dfg.signatures[sig_ref]
+                    .returns
+                    .iter()
+                    .map(|ret| ret.value_type)
+                    .eq(expected_returns()),
+                _ => continue,
+            };
+            if !returns_match {
+                return Err((inst, "return types don't match the new signature"));
+            }
+        }
+
+        let mut detached = Vec::new();
+        if let Some(entry) = self.layout.entry_block() {
+            let old_params = self.dfg.block_params(entry).to_vec();
+            for (i, &param) in old_params.iter().enumerate() {
+                match signature.params.get(i) {
+                    Some(abi_param) if abi_param.value_type == self.dfg.value_type(param) => {}
+                    Some(abi_param) => {
+                        self.dfg.replace_block_param(param, abi_param.value_type);
+                        detached.push(param);
+                    }
+                    None => {
+                        self.dfg.remove_block_param(param);
+                        detached.push(param);
+                    }
+                }
+            }
+            for abi_param in signature.params.iter().skip(old_params.len()) {
+                self.dfg.append_block_param(entry, abi_param.value_type);
+            }
+        }
+
+        self.signature = signature;
+        Ok(detached)
+    }
+
     /// Starts collection of debug information.
     pub fn collect_debug_info(&mut self) {
         self.dfg.collect_debug_info();
@@ -488,3 +584,62 @@ impl fmt::Debug for Function {
         write_function(fmt, self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cursor::{Cursor, FuncCursor};
+    use crate::ir::types::*;
+    use crate::ir::{AbiParam, InstBuilder};
+
+    fn sig(params: &[Type], returns: &[Type]) -> Signature {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params
+            .extend(params.iter().map(|&ty| AbiParam::new(ty)));
+        sig.returns
+            .extend(returns.iter().map(|&ty| AbiParam::new(ty)));
+        sig
+    }
+
+    #[test]
+    fn update_signature() {
+        let mut func =
+            Function::with_name_signature(UserFuncName::default(), sig(&[I32, I64, I8], &[I32]));
+        let block0 = func.dfg.make_block();
+        let v0 = func.dfg.append_block_param(block0, I32);
+        let v1 = func.dfg.append_block_param(block0, I64);
+        let v2 = func.dfg.append_block_param(block0, I8);
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        let ret = pos.ins().return_(&[v0]);
+
+        // Mismatched return types leave the function alone.
+        assert_eq!(
+            func.update_signature(sig(&[I32], &[I64])),
+            Err((ret, "return types don't match the new signature"))
+        );
+        assert_eq!(func.dfg.block_params(block0), &[v0, v1, v2]);
+        assert_eq!(func.signature, sig(&[I32, I64, I8], &[I32]));
+
+        // v0 is kept, v1 changes type and v2 is removed.
+        let detached = func.update_signature(sig(&[I32, F64], &[I32])).unwrap();
+        assert_eq!(detached, [v1, v2]);
+        let params = func.dfg.block_params(block0);
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0], v0);
+        assert_eq!(func.dfg.value_type(params[1]), F64);
+        assert!(!func.dfg.value_is_attached(v1));
+        assert!(!func.dfg.value_is_attached(v2));
+
+        // Missing parameters are appended.
+        let detached = func
+            .update_signature(sig(&[I32, F64, I16], &[I32]))
+            .unwrap();
+        assert!(detached.is_empty());
+        assert_eq!(
+            func.dfg.block_param_types(block0).collect::<Vec<_>>(),
+            [I32, F64, I16]
+        );
+        assert_eq!(func.signature, sig(&[I32, F64, I16], &[I32]));
+    }
+}
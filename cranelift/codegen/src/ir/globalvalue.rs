@@ -1,5 +1,6 @@
 //! Global values.
 
+use crate::ir::function::FunctionParameters;
 use crate::ir::immediates::{Imm64, Offset32};
 use crate::ir::{ExternalName, GlobalValue, MemFlags, Type};
 use crate::isa::TargetIsa;
@@ -100,24 +101,45 @@ impl GlobalValueData {
             Self::DynScaleTargetConst { .. } => isa.pointer_type(),
         }
     }
+
+    /// Returns a displayable version of the `GlobalValueData`, with or without extra context to
+    /// prettify the output.
+    pub fn display<'a>(
+        &'a self,
+        params: Option<&'a FunctionParameters>,
+    ) -> DisplayableGlobalValueData<'a> {
+        DisplayableGlobalValueData { data: self, params }
+    }
 }
 
 impl fmt::Display for GlobalValueData {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Self::VMContext => write!(f, "vmctx"),
-            Self::Load {
+        self.display(None).fmt(f)
+    }
+}
+
+/// A displayable `GlobalValueData`, with extra context to prettify the output.
+pub struct DisplayableGlobalValueData<'a> {
+    data: &'a GlobalValueData,
+    params: Option<&'a FunctionParameters>,
+}
+
+impl<'a> fmt::Display for DisplayableGlobalValueData<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.data {
+            GlobalValueData::VMContext => write!(f, "vmctx"),
+            GlobalValueData::Load {
                 base,
                 offset,
                 global_type,
                 flags,
             } => write!(f, "load.{}{} {}{}", global_type, flags, base, offset),
-            Self::IAddImm {
+            GlobalValueData::IAddImm {
                 global_type,
                 base,
                 offset,
             } => write!(f, "iadd_imm.{} {}, {}", global_type, base, offset),
-            Self::Symbol {
+            GlobalValueData::Symbol {
                 ref name,
                 offset,
                 colocated,
@@ -128,7 +150,7 @@ impl fmt::Display for GlobalValueData {
                     "symbol {}{}{}",
                     if colocated { "colocated " } else { "" },
                     if tls { "tls " } else { "" },
-                    name.display(None)
+                    name.display(self.params)
                 )?;
                 let offset_val: i64 = offset.into();
                 if offset_val > 0 {
@@ -139,7 +161,7 @@ impl fmt::Display for GlobalValueData {
                 }
                 Ok(())
             }
-            Self::DynScaleTargetConst { vector_type } => {
+            GlobalValueData::DynScaleTargetConst { vector_type } => {
                 write!(f, "dyn_scale_target_const.{}", vector_type)
             }
         }
@@ -1230,6 +1230,58 @@ mod tests {
         }
     }
 
+    // Verify that `x` prints as text that parses back to exactly `x`.
+    fn roundtrip<T: FromStr + Display + PartialEq + fmt::Debug>(x: T)
+    where
+        <T as FromStr>::Err: Display,
+    {
+        let text = x.to_string();
+        match text.parse::<T>() {
+            Err(s) => panic!("\"{}\".parse() error: {}", text, s),
+            Ok(y) => assert_eq!(y, x, "\"{}\" doesn't round-trip", text),
+        }
+    }
+
+    #[test]
+    fn roundtrip_all_kinds() {
+        // Edge cases of the various kinds, followed by a fixed pseudo-random sequence.
+        let mut bits = vec![
+            0,
+            1,
+            u64::MAX,
+            1 << 63,
+            (1 << 63) - 1,
+            u64::from(u32::MAX),
+            1 << 31,
+            (1 << 31) - 1,
+            0x7f80_0000,
+            0xffc0_0001,
+            0x0000_0001,
+            0x7ff0_0000_0000_0000,
+            0xfff8_0000_0000_0001,
+        ];
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            bits.push(state);
+        }
+
+        for &x in &bits {
+            roundtrip(Imm64::new(x as i64));
+            roundtrip(Uimm64::new(x));
+            roundtrip(Uimm32::from(x as u32));
+            // A zero offset prints as nothing, which the parser handles as an absent offset.
+            if x as i32 != 0 {
+                roundtrip(Offset32::new(x as i32));
+            }
+            roundtrip(Ieee32::with_bits(x as u32));
+            roundtrip(Ieee32::with_bits((x >> 32) as u32));
+            roundtrip(Ieee64::with_bits(x));
+        }
+    }
+
     #[test]
     fn parse_imm64() {
         parse_ok::<Imm64>("0", "0");
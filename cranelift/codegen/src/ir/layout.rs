@@ -9,6 +9,7 @@ use crate::ir::{Block, Inst};
 use crate::packed_option::PackedOption;
 use crate::{timing, trace};
 use core::cmp;
+use core::mem;
 
 /// The `Layout` struct determines the layout of blocks and instructions in a function. It does not
 /// contain definitions of instructions or blocks, but depends on `Inst` and `Block` entity references
@@ -59,6 +60,12 @@ impl Layout {
         self.last_block = None;
     }
 
+    /// Approximate number of heap bytes reserved by the layout's linked lists.
+    pub fn memory_usage(&self) -> usize {
+        self.blocks.capacity() * mem::size_of::<BlockNode>()
+            + self.insts.capacity() * mem::size_of::<InstNode>()
+    }
+
     /// Returns the capacity of the `BlockData` map.
     pub fn block_capacity(&self) -> usize {
         self.blocks.capacity()
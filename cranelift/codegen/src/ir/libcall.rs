@@ -43,6 +43,12 @@ pub enum LibCall {
     FmaF32,
     /// fma.f64
     FmaF64,
+    /// fpromote.f32 of an f16
+    PromoteF16ToF32,
+    /// fdemote.f16 of an f32
+    DemoteF32ToF16,
+    /// fdemote.f16 of an f64
+    DemoteF64ToF16,
     /// libc.memcpy
     Memcpy,
     /// libc.memset
@@ -84,6 +90,9 @@ impl FromStr for LibCall {
             "NearestF64" => Ok(Self::NearestF64),
             "FmaF32" => Ok(Self::FmaF32),
             "FmaF64" => Ok(Self::FmaF64),
+            "PromoteF16ToF32" => Ok(Self::PromoteF16ToF32),
+            "DemoteF32ToF16" => Ok(Self::DemoteF32ToF16),
+            "DemoteF64ToF16" => Ok(Self::DemoteF64ToF16),
             "Memcpy" => Ok(Self::Memcpy),
             "Memset" => Ok(Self::Memset),
             "Memmove" => Ok(Self::Memmove),
@@ -114,6 +123,9 @@ impl LibCall {
             NearestF64,
             FmaF32,
             FmaF64,
+            PromoteF16ToF32,
+            DemoteF32ToF16,
+            DemoteF64ToF16,
             Memcpy,
             Memset,
             Memmove,
@@ -146,6 +158,20 @@ impl LibCall {
                 sig.params.push(AbiParam::new(ty));
                 sig.returns.push(AbiParam::new(ty));
             }
+            LibCall::PromoteF16ToF32 => {
+                sig.params.push(AbiParam::new(F16));
+                sig.returns.push(AbiParam::new(F32));
+            }
+            LibCall::DemoteF32ToF16 | LibCall::DemoteF64ToF16 => {
+                let ty = if *self == LibCall::DemoteF32ToF16 {
+                    F32
+                } else {
+                    F64
+                };
+
+                sig.params.push(AbiParam::new(ty));
+                sig.returns.push(AbiParam::new(F16));
+            }
             LibCall::Memcpy | LibCall::Memmove => {
                 // void* memcpy(void *dest, const void *src, size_t count);
                 // void* memmove(void* dest, const void* src, size_t count);
@@ -219,6 +219,12 @@ impl MemFlags {
     }
 
     /// Set endianness of the memory access, returning new flags.
+    ///
+    /// Only s390x lowers accesses in the non-native byte order directly. Other targets legalize
+    /// integer, `f32`, `f64` and 128-bit vector loads and stores into a native access and a byte
+    /// swap. Anything else fails to compile with `CodegenError::Unsupported`, including extending
+    /// vector loads such as `uload8x8`, narrower vectors, `f16` and atomic accesses wider than a
+    /// byte.
     pub const fn with_endianness(self, endianness: Endianness) -> Self {
         let res = match endianness {
             Endianness::Little => self.with_bit(BIT_LITTLE_ENDIAN),
@@ -228,6 +234,16 @@ impl MemFlags {
         res
     }
 
+    /// Clear any explicitly specified endianness, returning new flags that access memory in the
+    /// native byte order.
+    ///
+    /// See `with_endianness` for the accesses that can't be compiled in the non-native byte
+    /// order.
+    pub const fn with_native_endianness(mut self) -> Self {
+        self.bits &= !(BIT_LITTLE_ENDIAN | BIT_BIG_ENDIAN);
+        self
+    }
+
     /// Test if this memory operation cannot trap.
     ///
     /// By default `MemFlags` will assume that any load/store can trap and is
@@ -412,9 +428,25 @@ impl fmt::Display for MemFlags {
     }
 }
 
+impl FromStr for MemFlags {
+    type Err = &'static str;
+
+    // Parse whitespace-separated flag names, formatted as above.
+    fn from_str(s: &str) -> Result<Self, &'static str> {
+        let mut flags = Self::new();
+        for name in s.split_whitespace() {
+            if !flags.set_by_name(name)? {
+                return Err("unknown memory flag");
+            }
+        }
+        Ok(flags)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn roundtrip_traps() {
@@ -426,6 +458,52 @@ mod tests {
         assert_eq!(flags.trap_code(), None);
     }
 
+    #[test]
+    fn roundtrip_display() {
+        let traps = [
+            None,
+            Some(TrapCode::HeapOutOfBounds),
+            Some(TrapCode::IntegerDivisionByZero),
+        ];
+        let endianness = [None, Some(Endianness::Little), Some(Endianness::Big)];
+        let regions = [
+            None,
+            Some(AliasRegion::Heap),
+            Some(AliasRegion::Table),
+            Some(AliasRegion::Vmctx),
+        ];
+        for trap in traps {
+            for endianness in endianness {
+                for region in regions {
+                    for bits in 0..8 {
+                        let mut flags = MemFlags::new()
+                            .with_trap_code(trap)
+                            .with_alias_region(region);
+                        if let Some(endianness) = endianness {
+                            flags.set_endianness(endianness);
+                        }
+                        if bits & 1 != 0 {
+                            flags.set_aligned();
+                        }
+                        if bits & 2 != 0 {
+                            flags.set_readonly();
+                        }
+                        if bits & 4 != 0 {
+                            flags.set_checked();
+                        }
+                        assert_eq!(flags.to_string().parse(), Ok(flags));
+                    }
+                }
+            }
+        }
+        assert_eq!("".parse(), Ok(MemFlags::new()));
+        assert_eq!("bogus".parse::<MemFlags>(), Err("unknown memory flag"));
+        assert_eq!(
+            "big little".parse::<MemFlags>(),
+            Err("cannot set both big and little endian bits")
+        );
+    }
+
     #[test]
     fn cannot_set_big_and_little() {
         let mut big = MemFlags::new().with_endianness(Endianness::Big);
@@ -435,6 +513,18 @@ mod tests {
         assert!(little.set_by_name("big").is_err());
     }
 
+    #[test]
+    fn native_endianness() {
+        let big = MemFlags::new().with_endianness(Endianness::Big);
+        let native = big.with_native_endianness();
+        assert_eq!(native.endianness(Endianness::Little), Endianness::Little);
+        assert_eq!(native.endianness(Endianness::Big), Endianness::Big);
+        assert_eq!(
+            native.with_endianness(Endianness::Little).to_string(),
+            " little"
+        );
+    }
+
     #[test]
     fn only_one_region() {
         let mut big = MemFlags::new().with_alias_region(Some(AliasRegion::Heap));
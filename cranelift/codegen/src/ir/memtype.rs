@@ -141,7 +141,7 @@ impl std::fmt::Display for MemoryTypeData {
                 write!(f, "memory {size:#x}")
             }
             Self::DynamicMemory { size, gv } => {
-                write!(f, "dynamic_memory {}+{:#x}", gv, size)
+                write!(f, "dynamic_memory {} + {:#x}", gv, size)
             }
             Self::Empty => {
                 write!(f, "empty")
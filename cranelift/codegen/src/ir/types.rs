@@ -14,7 +14,8 @@ use target_lexicon::{PointerWidth, Triple};
 ///
 /// Basic integer types: `I8`, `I16`, `I32`, `I64`, and `I128`. These types are sign-agnostic.
 ///
-/// Basic floating point types: `F32` and `F64`. IEEE single and double precision.
+/// Basic floating point types: `F16`, `F32`, and `F64`. IEEE half, single, and double precision.
+/// `F16` is a storage-only type that can be loaded, stored, and converted to the other formats.
 ///
 /// SIMD vector types have power-of-two lanes, up to 256. Lanes can be any int/float type.
 ///
@@ -56,7 +57,7 @@ impl Type {
     pub fn log2_lane_bits(self) -> u32 {
         match self.lane_type() {
             I8 => 3,
-            I16 => 4,
+            I16 | F16 => 4,
             I32 | F32 | R32 => 5,
             I64 | F64 | R64 => 6,
             I128 => 7,
@@ -68,7 +69,7 @@ impl Type {
     pub fn lane_bits(self) -> u32 {
         match self.lane_type() {
             I8 => 8,
-            I16 => 16,
+            I16 | F16 => 16,
             I32 | F32 | R32 => 32,
             I64 | F64 | R64 => 64,
             I128 => 128,
@@ -136,7 +137,7 @@ impl Type {
         // Replace the low 4 bits with the boolean version, preserve the high 4 bits.
         self.replace_lanes(match self.lane_type() {
             I8 => I8,
-            I16 => I16,
+            I16 | F16 => I16,
             I32 | F32 => I32,
             I64 | F64 => I64,
             R32 | R64 => panic!("Reference types are not truthy"),
@@ -163,7 +164,7 @@ impl Type {
     pub fn as_int(self) -> Self {
         self.replace_lanes(match self.lane_type() {
             I8 => I8,
-            I16 => I16,
+            I16 | F16 => I16,
             I32 | F32 | R32 => I32,
             I64 | F64 | R64 => I64,
             I128 => I128,
@@ -179,6 +180,7 @@ impl Type {
             I32 => I16,
             I64 => I32,
             I128 => I64,
+            F32 => F16,
             F64 => F32,
             _ => return None,
         }))
@@ -192,6 +194,7 @@ impl Type {
             I16 => I32,
             I32 => I64,
             I64 => I128,
+            F16 => F32,
             F32 => F64,
             _ => return None,
         }))
@@ -237,7 +240,7 @@ impl Type {
     /// Is this a scalar floating point type?
     pub fn is_float(self) -> bool {
         match self {
-            F32 | F64 => true,
+            F16 | F32 | F64 => true,
             _ => false,
         }
     }
@@ -487,6 +490,7 @@ mod tests {
         assert_eq!(I32, I32.lane_type());
         assert_eq!(I64, I64.lane_type());
         assert_eq!(I128, I128.lane_type());
+        assert_eq!(F16, F16.lane_type());
         assert_eq!(F32, F32.lane_type());
         assert_eq!(F64, F64.lane_type());
         assert_eq!(I32, I32X4.lane_type());
@@ -500,6 +504,7 @@ mod tests {
         assert_eq!(I32.lane_bits(), 32);
         assert_eq!(I64.lane_bits(), 64);
         assert_eq!(I128.lane_bits(), 128);
+        assert_eq!(F16.lane_bits(), 16);
         assert_eq!(F32.lane_bits(), 32);
         assert_eq!(F64.lane_bits(), 64);
         assert_eq!(R32.lane_bits(), 32);
@@ -516,7 +521,8 @@ mod tests {
         assert_eq!(I32X4.half_width(), Some(I16X4));
         assert_eq!(I64.half_width(), Some(I32));
         assert_eq!(I128.half_width(), Some(I64));
-        assert_eq!(F32.half_width(), None);
+        assert_eq!(F16.half_width(), None);
+        assert_eq!(F32.half_width(), Some(F16));
         assert_eq!(F64.half_width(), Some(F32));
 
         assert_eq!(INVALID.double_width(), None);
@@ -526,6 +532,7 @@ mod tests {
         assert_eq!(I32X4.double_width(), Some(I64X4));
         assert_eq!(I64.double_width(), Some(I128));
         assert_eq!(I128.double_width(), None);
+        assert_eq!(F16.double_width(), Some(F32));
         assert_eq!(F32.double_width(), Some(F64));
         assert_eq!(F64.double_width(), None);
     }
@@ -586,6 +593,7 @@ mod tests {
         assert_eq!(I32.to_string(), "i32");
         assert_eq!(I64.to_string(), "i64");
         assert_eq!(I128.to_string(), "i128");
+        assert_eq!(F16.to_string(), "f16");
         assert_eq!(F32.to_string(), "f32");
         assert_eq!(F64.to_string(), "f64");
         assert_eq!(R32.to_string(), "r32");
@@ -596,6 +604,7 @@ mod tests {
     fn format_vectors() {
         assert_eq!(I8.by(64).unwrap().to_string(), "i8x64");
         assert_eq!(F64.by(2).unwrap().to_string(), "f64x2");
+        assert_eq!(F16.by(8).unwrap().to_string(), "f16x8");
         assert_eq!(I8.by(3), None);
         assert_eq!(I8.by(512), None);
         assert_eq!(INVALID.by(4), None);
@@ -6283,6 +6283,50 @@ fn test_aarch64_binemit() {
         "fcvt s15, d30",
     ));
 
+    insns.push((
+        Inst::FpuRR {
+            fpu_op: FPUOp1::Cvt16To32,
+            size: ScalarSize::Size16,
+            rd: writable_vreg(15),
+            rn: vreg(30),
+        },
+        "CF43E21E",
+        "fcvt s15, h30",
+    ));
+
+    insns.push((
+        Inst::FpuRR {
+            fpu_op: FPUOp1::Cvt16To64,
+            size: ScalarSize::Size16,
+            rd: writable_vreg(15),
+            rn: vreg(30),
+        },
+        "CFC3E21E",
+        "fcvt d15, h30",
+    ));
+
+    insns.push((
+        Inst::FpuRR {
+            fpu_op: FPUOp1::Cvt32To16,
+            size: ScalarSize::Size32,
+            rd: writable_vreg(15),
+            rn: vreg(30),
+        },
+        "CFC3231E",
+        "fcvt h15, s30",
+    ));
+
+    insns.push((
+        Inst::FpuRR {
+            fpu_op: FPUOp1::Cvt64To16,
+            size: ScalarSize::Size64,
+            rd: writable_vreg(15),
+            rn: vreg(30),
+        },
+        "CFC3631E",
+        "fcvt h15, d30",
+    ));
+
     insns.push((
         Inst::FpuRRR {
             fpu_op: FPUOp2::Add,
@@ -6675,6 +6719,19 @@ fn test_aarch64_binemit() {
         "fcmp d23, d24",
     ));
 
+    insns.push((
+        Inst::FpuLoad16 {
+            rd: writable_vreg(16),
+            mem: AMode::RegScaled {
+                rn: xreg(8),
+                rm: xreg(9),
+            },
+            flags: MemFlags::trusted(),
+        },
+        "1079697C",
+        "ldr h16, [x8, x9, LSL #1]",
+    ));
+
     insns.push((
         Inst::FpuLoad32 {
             rd: writable_vreg(16),
@@ -6750,6 +6807,19 @@ fn test_aarch64_binemit() {
         "ldr q16, pc+8",
     ));
 
+    insns.push((
+        Inst::FpuStore16 {
+            rd: vreg(16),
+            mem: AMode::RegScaled {
+                rn: xreg(8),
+                rm: xreg(9),
+            },
+            flags: MemFlags::trusted(),
+        },
+        "1079297C",
+        "str h16, [x8, x9, LSL #1]",
+    ));
+
     insns.push((
         Inst::FpuStore32 {
             rd: vreg(16),
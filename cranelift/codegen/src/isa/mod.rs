@@ -369,6 +369,11 @@ pub trait TargetIsa: fmt::Display + Send + Sync {
     /// Returns whether the CLIF `x86_pmaddubsw` instruction is implemented for
     /// this ISA.
     fn has_x86_pmaddubsw_lowering(&self) -> bool;
+
+    /// Returns whether loads and stores whose `MemFlags` request the
+    /// non-native byte order are implemented for this ISA. If not, the
+    /// legalizer rewrites them into native-order accesses plus a `bswap`.
+    fn has_byte_swapped_memory_lowering(&self) -> bool;
 }
 
 /// Function alignment specifications as required by an ISA, returned by
@@ -86,12 +86,15 @@ impl TargetIsa for S390xBackend {
         Ok(CompiledCodeStencil {
             buffer,
             frame_size,
+            frame_sizes: emit_result.frame_sizes,
             vcode: emit_result.disasm,
             value_labels_ranges,
             sized_stackslot_offsets,
             dynamic_stackslot_offsets,
             bb_starts: emit_result.bb_offsets,
             bb_edges: emit_result.bb_edges,
+            param_locations: emit_result.param_locations,
+            return_locations: emit_result.return_locations,
         })
     }
 
@@ -188,6 +191,10 @@ impl TargetIsa for S390xBackend {
     fn has_x86_pmaddubsw_lowering(&self) -> bool {
         false
     }
+
+    fn has_byte_swapped_memory_lowering(&self) -> bool {
+        true
+    }
 }
 
 impl fmt::Display for S390xBackend {
@@ -15,11 +15,15 @@
 
 use crate::cursor::{Cursor, FuncCursor};
 use crate::flowgraph::ControlFlowGraph;
-use crate::ir::immediates::Imm64;
-use crate::ir::types::{self, I128, I64};
-use crate::ir::{self, InstBuilder, InstructionData, MemFlags, Value};
+use crate::ir::immediates::{Imm64, Offset32};
+use crate::ir::types::{self, F32, F64, I128, I16, I32, I64, I8X16};
+use crate::ir::{self, InstBuilder, InstructionData, MemFlags, Type, Value};
 use crate::isa::TargetIsa;
+use crate::result::{CodegenError, CodegenResult};
+use crate::timing;
 use crate::trace;
+use alloc::format;
+use alloc::vec::Vec;
 
 mod globalvalue;
 
@@ -52,7 +56,12 @@ fn imm_const(pos: &mut FuncCursor, arg: Value, imm: Imm64, is_signed: bool) -> V
 
 /// Perform a simple legalization by expansion of the function, without
 /// platform-specific transforms.
-pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa: &dyn TargetIsa) {
+pub fn simple_legalize(
+    func: &mut ir::Function,
+    cfg: &mut ControlFlowGraph,
+    isa: &dyn TargetIsa,
+) -> CodegenResult<()> {
+    let _tt = timing::legalize();
     trace!("Pre-legalization function:\n{}", func.display());
 
     let mut pos = FuncCursor::new(func);
@@ -72,6 +81,20 @@ pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa:
                     expand_cond_trap(inst, &mut pos.func, cfg, opcode, arg, code);
                 }
 
+                // checked arithmetic
+                InstructionData::IntAddTrap {
+                    opcode:
+                        opcode @ (ir::Opcode::SaddOverflowTrap
+                        | ir::Opcode::UsubOverflowTrap
+                        | ir::Opcode::SsubOverflowTrap
+                        | ir::Opcode::UmulOverflowTrap
+                        | ir::Opcode::SmulOverflowTrap),
+                    args,
+                    code,
+                } => {
+                    expand_overflow_trap(inst, &mut pos.func, opcode, args, code);
+                }
+
                 // memory and constants
                 InstructionData::UnaryGlobalValue {
                     opcode: ir::Opcode::GlobalValue,
@@ -115,6 +138,63 @@ pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa:
                     mflags.set_notrap();
                     pos.func.dfg.replace(inst).store(mflags, arg, addr, 0);
                 }
+                InstructionData::Load {
+                    opcode,
+                    flags,
+                    arg,
+                    offset,
+                } if is_byte_swapped(flags, isa) => {
+                    if !expand_byte_swapped_load(
+                        inst,
+                        &mut pos.func,
+                        isa,
+                        opcode,
+                        flags,
+                        arg,
+                        offset,
+                    )? {
+                        prev_pos = pos.position();
+                        continue;
+                    }
+                }
+                InstructionData::Store {
+                    opcode,
+                    flags,
+                    args,
+                    offset,
+                } if is_byte_swapped(flags, isa) => {
+                    if !expand_byte_swapped_store(
+                        inst,
+                        &mut pos.func,
+                        isa,
+                        opcode,
+                        flags,
+                        args,
+                        offset,
+                    )? {
+                        prev_pos = pos.position();
+                        continue;
+                    }
+                }
+                // Atomic accesses wider than a byte can't be split into a native access and a
+                // byte swap without changing what the atomic operation computes.
+                InstructionData::LoadNoOffset {
+                    opcode: ir::Opcode::AtomicLoad,
+                    flags,
+                    ..
+                }
+                | InstructionData::StoreNoOffset {
+                    opcode: ir::Opcode::AtomicStore,
+                    flags,
+                    ..
+                }
+                | InstructionData::AtomicRmw { flags, .. }
+                | InstructionData::AtomicCas { flags, .. }
+                    if is_byte_swapped(flags, isa)
+                        && pos.func.dfg.ctrl_typevar(inst).bytes() > 1 =>
+                {
+                    return Err(unsupported_byte_order(pos.func, inst));
+                }
                 InstructionData::DynamicStackLoad {
                     opcode: ir::Opcode::DynamicStackLoad,
                     dynamic_stack_slot,
@@ -263,6 +343,186 @@ pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa:
     }
 
     trace!("Post-legalization function:\n{}", func.display());
+    Ok(())
+}
+
+/// Whether `flags` request a byte order other than the native one of `isa`, and `isa` can't lower
+/// such accesses itself.
+///
+/// Only s390x can. Everywhere else, byte-swapped extending vector loads such as `uload8x8` are
+/// rejected by `expand_byte_swapped_load` as unsupported.
+fn is_byte_swapped(flags: MemFlags, isa: &dyn TargetIsa) -> bool {
+    let native = isa.endianness();
+    flags.endianness(native) != native && !isa.has_byte_swapped_memory_lowering()
+}
+
+/// The integer type to byte-swap a `ty` value in memory as, if any.
+fn byte_swap_type(ty: Type) -> Option<Type> {
+    match ty {
+        I16 | I32 | I64 | I128 => Some(ty),
+        F32 => Some(I32),
+        F64 => Some(I64),
+        _ => None,
+    }
+}
+
+fn unsupported_byte_order(func: &ir::Function, inst: ir::Inst) -> CodegenError {
+    CodegenError::Unsupported(format!(
+        "non-native byte order in `{}`",
+        func.dfg.display_inst(inst)
+    ))
+}
+
+/// Reverse the order of all bytes in the 128-bit vector `x`.
+fn byte_swap_vector(pos: &mut FuncCursor, x: Value) -> Value {
+    let mask: Vec<u8> = (0..16).rev().collect();
+    let mask = pos.func.dfg.immediates.push(mask.into());
+    pos.ins().shuffle(x, x, mask)
+}
+
+/// Expand a load in the non-native byte order into a native load followed by a `bswap`.
+///
+/// A 128-bit vector is swapped as a whole, like an `i128`, so the lane order is reversed too. That
+/// takes a shuffle instead of a `bswap`.
+///
+/// Returns `false`, leaving the load alone, if a single byte is loaded.
+fn expand_byte_swapped_load(
+    inst: ir::Inst,
+    func: &mut ir::Function,
+    isa: &dyn TargetIsa,
+    opcode: ir::Opcode,
+    flags: MemFlags,
+    arg: Value,
+    offset: Offset32,
+) -> CodegenResult<bool> {
+    let ty = func.dfg.ctrl_typevar(inst);
+    let mem_ty = match opcode {
+        ir::Opcode::Load => ty,
+        ir::Opcode::Uload16 | ir::Opcode::Sload16 => I16,
+        ir::Opcode::Uload32 | ir::Opcode::Sload32 => I32,
+        ir::Opcode::Uload8 | ir::Opcode::Sload8 => return Ok(false),
+        _ => return Err(unsupported_byte_order(func, inst)),
+    };
+    if mem_ty.bytes() == 1 {
+        return Ok(false);
+    }
+
+    let mut pos = FuncCursor::new(func).at_inst(inst);
+    pos.use_srcloc(inst);
+
+    if mem_ty.is_vector() && mem_ty.bits() == 128 {
+        let bytes = pos
+            .ins()
+            .load(I8X16, flags.with_native_endianness(), arg, offset);
+        let swapped = byte_swap_vector(&mut pos, bytes);
+        let lane_order = MemFlags::new().with_endianness(isa.endianness());
+        pos.func.dfg.replace(inst).bitcast(ty, lane_order, swapped);
+        return Ok(true);
+    }
+
+    let Some(int_ty) = byte_swap_type(mem_ty) else {
+        return Err(unsupported_byte_order(pos.func, inst));
+    };
+    let bytes = pos
+        .ins()
+        .load(int_ty, flags.with_native_endianness(), arg, offset);
+    if ty == int_ty {
+        pos.func.dfg.replace(inst).bswap(bytes);
+        return Ok(true);
+    }
+
+    let swapped = pos.ins().bswap(bytes);
+    let replace = pos.func.dfg.replace(inst);
+    match opcode {
+        ir::Opcode::Load => replace.bitcast(ty, MemFlags::new(), swapped),
+        ir::Opcode::Uload16 | ir::Opcode::Uload32 => replace.uextend(ty, swapped),
+        _ => replace.sextend(ty, swapped),
+    };
+    Ok(true)
+}
+
+/// Expand a store in the non-native byte order into a `bswap` followed by a native store.
+///
+/// 128-bit vectors are swapped as a whole with a shuffle, like in `expand_byte_swapped_load`.
+///
+/// Returns `false`, leaving the store alone, if a single byte is stored.
+fn expand_byte_swapped_store(
+    inst: ir::Inst,
+    func: &mut ir::Function,
+    isa: &dyn TargetIsa,
+    opcode: ir::Opcode,
+    flags: MemFlags,
+    args: [Value; 2],
+    offset: Offset32,
+) -> CodegenResult<bool> {
+    let [x, addr] = args;
+    let ty = func.dfg.value_type(x);
+    let mem_ty = match opcode {
+        ir::Opcode::Store => ty,
+        ir::Opcode::Istore16 => I16,
+        ir::Opcode::Istore32 => I32,
+        _ => return Ok(false),
+    };
+    if mem_ty.bytes() == 1 {
+        return Ok(false);
+    }
+
+    let mut pos = FuncCursor::new(func).at_inst(inst);
+    pos.use_srcloc(inst);
+
+    let swapped = if mem_ty.is_vector() && mem_ty.bits() == 128 {
+        let lane_order = MemFlags::new().with_endianness(isa.endianness());
+        let bytes = pos.ins().bitcast(I8X16, lane_order, x);
+        byte_swap_vector(&mut pos, bytes)
+    } else {
+        let Some(int_ty) = byte_swap_type(mem_ty) else {
+            return Err(unsupported_byte_order(pos.func, inst));
+        };
+        let bits = if ty == int_ty {
+            x
+        } else if opcode == ir::Opcode::Store {
+            pos.ins().bitcast(int_ty, MemFlags::new(), x)
+        } else {
+            pos.ins().ireduce(int_ty, x)
+        };
+        pos.ins().bswap(bits)
+    };
+    pos.func
+        .dfg
+        .replace(inst)
+        .store(flags.with_native_endianness(), swapped, addr, offset);
+    Ok(true)
+}
+
+/// Expand a trapping arithmetic instruction into the matching `*_overflow` instruction followed
+/// by a `trapnz` on its overflow flag.
+///
+/// The `trapnz` is expanded in turn when the legalizer revisits it.
+fn expand_overflow_trap(
+    inst: ir::Inst,
+    func: &mut ir::Function,
+    opcode: ir::Opcode,
+    args: [ir::Value; 2],
+    code: ir::TrapCode,
+) {
+    let [x, y] = args;
+    let result = func.dfg.first_result(inst);
+    func.dfg.clear_results(inst);
+
+    let replace = func.dfg.replace(inst);
+    let (value, overflow) = match opcode {
+        ir::Opcode::SaddOverflowTrap => replace.sadd_overflow(x, y),
+        ir::Opcode::UsubOverflowTrap => replace.usub_overflow(x, y),
+        ir::Opcode::SsubOverflowTrap => replace.ssub_overflow(x, y),
+        ir::Opcode::UmulOverflowTrap => replace.umul_overflow(x, y),
+        ir::Opcode::SmulOverflowTrap => replace.smul_overflow(x, y),
+        _ => unreachable!(),
+    };
+    func.dfg.change_to_alias(result, value);
+
+    let mut pos = FuncCursor::new(func).after_inst(inst);
+    pos.use_srcloc(inst);
+    pos.ins().trapnz(overflow, code);
 }
 
 /// Custom expansion for conditional trap instructions.
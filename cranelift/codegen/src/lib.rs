@@ -24,7 +24,7 @@ use std::collections::{hash_map, HashMap};
 
 pub use crate::context::Context;
 pub use crate::value_label::{LabelValueLoc, ValueLabelsRanges, ValueLocRange};
-pub use crate::verifier::verify_function;
+pub use crate::verifier::{validate_quick, verify_function};
 pub use crate::write::write_function;
 
 pub use cranelift_bforest as bforest;
@@ -38,6 +38,7 @@ mod machinst;
 
 pub mod binemit;
 pub mod cfg_printer;
+pub mod coverage;
 pub mod cursor;
 pub mod data_value;
 pub mod dbg;
@@ -49,6 +50,7 @@ pub mod loop_analysis;
 pub mod print_errors;
 pub mod settings;
 pub mod timing;
+pub mod use_lists;
 pub mod verifier;
 pub mod write;
 
@@ -58,7 +60,7 @@ pub use crate::machinst::buffer::{
     MachTextSectionBuilder, MachTrap, OpenPatchRegion, PatchRegion,
 };
 pub use crate::machinst::{
-    CompiledCode, Final, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit,
+    CompiledCode, Final, FrameSizes, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit,
     MachInstEmitState, MachLabel, RealReg, Reg, RelocDistance, TextSectionBuilder,
     VCodeConstantData, VCodeConstants, Writable,
 };
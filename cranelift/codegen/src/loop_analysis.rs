@@ -1,5 +1,12 @@
 //! A loop analysis represented as mappings of loops to their header Block
 //! and parent in the loop tree.
+//!
+//! Only natural loops are represented: cycles with a single header block that dominates every
+//! block in the cycle. Irreducible control flow, where a cycle can be entered at more than one
+//! block, is valid IR but is not turned into loops. Passes that use this analysis, such as the
+//! loop-invariant code motion done during egraph elaboration, leave such cycles alone. Use
+//! `LoopAnalysis::has_irreducible_control_flow` to find out if a function contains any, or the
+//! `reject_irreducible_control_flow` setting to have the verifier report them as errors.
 
 use crate::dominator_tree::DominatorTree;
 use crate::entity::entity_impl;
@@ -10,6 +17,8 @@ use crate::ir::{Block, Function, Layout};
 use crate::packed_option::PackedOption;
 use crate::timing;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::mem;
 use smallvec::{smallvec, SmallVec};
 
 /// A opaque reference to a code loop.
@@ -24,6 +33,7 @@ entity_impl!(Loop, "loop");
 pub struct LoopAnalysis {
     loops: PrimaryMap<Loop, LoopData>,
     block_loop_map: SecondaryMap<Block, PackedOption<Loop>>,
+    irreducible: bool,
     valid: bool,
 }
 
@@ -94,6 +104,7 @@ impl LoopAnalysis {
             valid: false,
             loops: PrimaryMap::new(),
             block_loop_map: SecondaryMap::new(),
+            irreducible: false,
         }
     }
 
@@ -166,6 +177,7 @@ impl LoopAnalysis {
         self.loops.clear();
         self.block_loop_map.clear();
         self.block_loop_map.resize(func.dfg.num_blocks());
+        self.irreducible = false;
         self.find_loop_headers(cfg, domtree, &func.layout);
         self.discover_loop_blocks(cfg, domtree, &func.layout);
         self.assign_loop_levels();
@@ -181,17 +193,34 @@ impl LoopAnalysis {
         self.valid
     }
 
+    /// Check if the function has irreducible control flow, i.e. a cycle that can be entered at
+    /// more than one block.
+    ///
+    /// The blocks of such a cycle are not part of any loop reported by this analysis, unless the
+    /// cycle is itself nested inside a natural loop.
+    pub fn has_irreducible_control_flow(&self) -> bool {
+        self.irreducible
+    }
+
     /// Clear all the data structures contained in the loop analysis. This will leave the
     /// analysis in a similar state to a context returned by `new()` except that allocated
     /// memory be retained.
     pub fn clear(&mut self) {
         self.loops.clear();
         self.block_loop_map.clear();
+        self.irreducible = false;
         self.valid = false;
     }
 
+    /// Approximate number of heap bytes reserved by the loop analysis.
+    pub fn memory_usage(&self) -> usize {
+        self.loops.capacity() * mem::size_of::<LoopData>()
+            + self.block_loop_map.capacity() * mem::size_of::<PackedOption<Loop>>()
+    }
+
     // Traverses the CFG in reverse postorder and create a loop object for every block having a
-    // back edge.
+    // back edge. Retreating edges that are not back edges are recorded as irreducible control
+    // flow.
     fn find_loop_headers(
         &mut self,
         cfg: &ControlFlowGraph,
@@ -200,19 +229,28 @@ impl LoopAnalysis {
     ) {
         // We traverse the CFG in reverse postorder
         for &block in domtree.cfg_postorder().iter().rev() {
+            let mut is_header = false;
             for BlockPredecessor {
-                inst: pred_inst, ..
+                block: pred,
+                inst: pred_inst,
             } in cfg.pred_iter(block)
             {
                 // If the block dominates one of its predecessors it is a back edge
                 if domtree.dominates(block, pred_inst, layout) {
-                    // This block is a loop header, so we create its associated loop
-                    let lp = self.loops.push(LoopData::new(block, None));
-                    self.block_loop_map[block] = lp.into();
-                    break;
-                    // We break because we only need one back edge to identify a loop header.
+                    is_header = true;
+                } else if domtree.is_reachable(pred)
+                    && domtree.rpo_cmp_block(block, pred) != Ordering::Greater
+                {
+                    // An edge going backwards in reverse postorder to a block that doesn't
+                    // dominate its source enters a cycle from the side.
+                    self.irreducible = true;
                 }
             }
+            if is_header {
+                // This block is a loop header, so we create its associated loop
+                let lp = self.loops.push(LoopData::new(block, None));
+                self.block_loop_map[block] = lp.into();
+            }
         }
     }
 
@@ -441,5 +479,46 @@ mod tests {
         assert_eq!(loop_analysis.loop_level(block3).level(), 2);
         assert_eq!(loop_analysis.loop_level(block4).level(), 2);
         assert_eq!(loop_analysis.loop_level(block5).level(), 1);
+        assert!(!loop_analysis.has_irreducible_control_flow());
+    }
+
+    #[test]
+    fn irreducible_loop_detection() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        let block1 = func.dfg.make_block();
+        let block2 = func.dfg.make_block();
+        let block3 = func.dfg.make_block();
+        let cond = func.dfg.append_block_param(block0, types::I32);
+
+        {
+            let mut cur = FuncCursor::new(&mut func);
+
+            // The cycle between block1 and block2 can be entered at either block.
+            cur.insert_block(block0);
+            cur.ins().brif(cond, block1, &[], block2, &[]);
+
+            cur.insert_block(block1);
+            cur.ins().jump(block2, &[]);
+
+            cur.insert_block(block2);
+            cur.ins().brif(cond, block1, &[], block3, &[]);
+
+            cur.insert_block(block3);
+            cur.ins().return_(&[]);
+        }
+
+        let mut loop_analysis = LoopAnalysis::new();
+        let cfg = ControlFlowGraph::with_function(&func);
+        let domtree = DominatorTree::with_function(&func, &cfg);
+        loop_analysis.compute(&func, &cfg, &domtree);
+
+        assert_eq!(loop_analysis.loops().count(), 0);
+        assert!(loop_analysis.has_irreducible_control_flow());
+        assert_eq!(loop_analysis.loop_level(block1).level(), 0);
+        assert_eq!(loop_analysis.loop_level(block2).level(), 0);
+
+        loop_analysis.clear();
+        assert!(!loop_analysis.has_irreducible_control_flow());
     }
 }
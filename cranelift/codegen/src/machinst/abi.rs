@@ -177,6 +177,60 @@ impl ABIArgSlot {
             ABIArgSlot::Stack { ty, .. } => *ty,
         }
     }
+
+    /// Where this slot lives at the function boundary.
+    pub fn location(&self) -> ArgLocation {
+        match self {
+            ABIArgSlot::Reg { reg, .. } => ArgLocation::Reg(*reg),
+            ABIArgSlot::Stack { offset, .. } => ArgLocation::Stack(*offset),
+        }
+    }
+}
+
+/// Where a register-sized part of a parameter or return value lives on entry to or exit from a
+/// compiled function.
+///
+/// This is a simplified view of `ABIArgSlot` kept in compilation results, for consumers such as
+/// debuggers and unwinders that need to find parameters without redoing the ABI computation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "enable-serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub enum ArgLocation {
+    /// In a real register.
+    Reg(RealReg),
+    /// On the stack, at the given offset from the base of the stack arguments for parameters,
+    /// or from the base of the return-value area for return values.
+    Stack(i64),
+}
+
+/// The locations of all parts of one parameter or return value, in the same order as the
+/// parts of its `ABIArg`.
+pub type ArgLocations = SmallVec<[ArgLocation; 1]>;
+
+/// The sizes of the areas of a function's stack frame, as laid out after register allocation.
+///
+/// Each size includes any padding the ABI requires. Together with the stack slot offsets, this
+/// shows where the bytes of a function's frame go.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "enable-serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct FrameSizes {
+    /// The return address and saved frame pointer, if any.
+    pub setup_area: u32,
+    /// Saved callee-saved registers that the function clobbers.
+    pub clobbers: u32,
+    /// The function's sized and dynamic stack slots.
+    pub stack_slots: u32,
+    /// Spill slots created by the register allocator.
+    pub spill_slots: u32,
+    /// Padding after the stack and spill slots that keeps the stack pointer aligned.
+    pub padding: u32,
+    /// Space reserved for the arguments of outgoing calls.
+    pub outgoing_args: u32,
 }
 
 /// A vector of `ABIArgSlot`s. Inline capacity for one element because basically
@@ -238,6 +292,26 @@ pub enum ABIArg {
 }
 
 impl ABIArg {
+    /// Where this argument lives at the function boundary.
+    ///
+    /// Arguments passed by reference report the location of the pointer to their buffer, or the
+    /// location of the buffer itself when the ABI places it at a well-known stack offset.
+    pub fn locations(&self) -> ArgLocations {
+        match self {
+            ABIArg::Slots { slots, .. } => slots.iter().map(ABIArgSlot::location).collect(),
+            ABIArg::StructArg {
+                pointer: Some(pointer),
+                ..
+            }
+            | ABIArg::ImplicitPtrArg { pointer, .. } => smallvec![pointer.location()],
+            ABIArg::StructArg {
+                pointer: None,
+                offset,
+                ..
+            } => smallvec![ArgLocation::Stack(*offset)],
+        }
+    }
+
     /// Create an ABIArg from one register.
     pub fn reg(
         reg: RealReg,
@@ -1085,6 +1159,8 @@ pub struct Callee<M: ABIMachineSpec> {
     sized_stackslots: PrimaryMap<StackSlot, u32>,
     /// Total stack size of all stackslots
     stackslots_size: u32,
+    /// Total stack size of the register allocator's spillslots, set along with the frame layout.
+    spillslots_size: u32,
     /// Stack size to be reserved for outgoing arguments.
     outgoing_args_size: u32,
     /// Initially the number of bytes originating in the callers frame where stack arguments will
@@ -1234,6 +1310,7 @@ impl<M: ABIMachineSpec> Callee<M> {
             dynamic_type_sizes,
             sized_stackslots,
             stackslots_size,
+            spillslots_size: 0,
             outgoing_args_size: 0,
             tail_args_size,
             reg_args: vec![],
@@ -1453,6 +1530,21 @@ impl<M: ABIMachineSpec> Callee<M> {
         M::get_machine_env(&self.flags, self.call_conv(sigs))
     }
 
+    /// The ABI locations of this function's parameters, one entry per parameter of the
+    /// signature, not counting the implicit return-area pointer.
+    pub fn param_locations(&self, sigs: &SigSet) -> Vec<ArgLocations> {
+        sigs.args(self.sig)[..sigs.num_args(self.sig)]
+            .iter()
+            .map(ABIArg::locations)
+            .collect()
+    }
+
+    /// The ABI locations of this function's return values, one entry per return value of the
+    /// (possibly legalized) signature.
+    pub fn return_locations(&self, sigs: &SigSet) -> Vec<ArgLocations> {
+        sigs.rets(self.sig).iter().map(ABIArg::locations).collect()
+    }
+
     /// The offsets of all sized stack slots (not spill slots) for debuginfo purposes.
     pub fn sized_stackslot_offsets(&self) -> &PrimaryMap<StackSlot, u32> {
         &self.sized_stackslots
@@ -1782,7 +1874,8 @@ impl<M: ABIMachineSpec> Callee<M> {
         clobbered: Vec<Writable<RealReg>>,
     ) {
         let bytes = M::word_bytes();
-        let total_stacksize = self.stackslots_size + bytes * spillslots as u32;
+        self.spillslots_size = bytes * spillslots as u32;
+        let total_stacksize = self.stackslots_size + self.spillslots_size;
         let mask = M::stack_align(self.call_conv) - 1;
         let total_stacksize = (total_stacksize + mask) & !mask; // 16-align the stack.
         self.frame_layout = Some(M::compute_frame_layout(
@@ -1938,6 +2031,21 @@ impl<M: ABIMachineSpec> Callee<M> {
         frame_layout.clobber_size + frame_layout.fixed_frame_storage_size
     }
 
+    /// Returns the sizes of the areas of the stack frame, after prologue emission has run.
+    pub fn frame_sizes(&self) -> FrameSizes {
+        let frame_layout = self.frame_layout();
+        FrameSizes {
+            setup_area: frame_layout.setup_area_size,
+            clobbers: frame_layout.clobber_size,
+            stack_slots: self.stackslots_size,
+            spill_slots: self.spillslots_size,
+            padding: frame_layout.fixed_frame_storage_size
+                - self.stackslots_size
+                - self.spillslots_size,
+            outgoing_args: frame_layout.outgoing_args_size,
+        }
+    }
+
     /// Returns offset from the nominal SP to caller's SP.
     pub fn nominal_sp_to_caller_sp_offset(&self) -> u32 {
         let frame_layout = self.frame_layout();
@@ -2503,4 +2611,165 @@ mod tests {
         // we don't regress it unintentionally.
         assert_eq!(std::mem::size_of::<SigData>(), 24);
     }
+
+    #[test]
+    #[cfg(feature = "x86")]
+    fn param_and_return_locations() {
+        use super::ArgLocation;
+        use crate::cursor::{Cursor, FuncCursor};
+        use crate::ir::{types, AbiParam, Function, InstBuilder, Signature, UserFuncName};
+        use crate::isa::{lookup, CallConv};
+        use crate::settings::{builder, Flags};
+        use crate::Context;
+        use target_lexicon::triple;
+
+        let isa = lookup(triple!("x86_64"))
+            .expect("expect x86 ISA")
+            .finish(Flags::new(builder()))
+            .expect("expect backend creation to succeed");
+
+        // Seven integer parameters: six are passed in registers, the last on the stack.
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params.resize(7, AbiParam::new(types::I64));
+        sig.returns.push(AbiParam::new(types::I64));
+        let mut func = Function::with_name_signature(UserFuncName::default(), sig);
+        let block0 = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        for _ in 0..7 {
+            pos.func.dfg.append_block_param(block0, types::I64);
+        }
+        let last = pos.func.dfg.block_params(block0)[6];
+        pos.ins().return_(&[last]);
+
+        let mut context = Context::for_function(func);
+        let code = context
+            .compile(&*isa, &mut Default::default())
+            .expect("expected compilation");
+
+        let reg_encodings: alloc::vec::Vec<_> = code.param_locations[..6]
+            .iter()
+            .map(|locs| match locs[..] {
+                [ArgLocation::Reg(reg)] => reg.hw_enc(),
+                _ => panic!("expected a single register, got {:?}", locs),
+            })
+            .collect();
+        // rdi, rsi, rdx, rcx, r8, r9
+        assert_eq!(reg_encodings, [7, 6, 2, 1, 8, 9]);
+        assert_eq!(code.param_locations[6][..], [ArgLocation::Stack(0)]);
+
+        assert_eq!(code.return_locations.len(), 1);
+        match code.return_locations[0][..] {
+            // rax
+            [ArgLocation::Reg(reg)] => assert_eq!(reg.hw_enc(), 0),
+            ref locs => panic!("expected a single register, got {:?}", locs),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "x86")]
+    fn frame_sizes() {
+        use crate::cursor::{Cursor, FuncCursor};
+        use crate::ir::{
+            types, Function, InstBuilder, Signature, StackSlotData, StackSlotKind, UserFuncName,
+        };
+        use crate::isa::{lookup, CallConv};
+        use crate::settings::{builder, Flags};
+        use crate::Context;
+        use target_lexicon::triple;
+
+        let isa = lookup(triple!("x86_64"))
+            .expect("expect x86 ISA")
+            .finish(Flags::new(builder()))
+            .expect("expect backend creation to succeed");
+
+        let mut func = Function::with_name_signature(
+            UserFuncName::default(),
+            Signature::new(CallConv::SystemV),
+        );
+        let ss0 = func.create_sized_stack_slot(StackSlotData::new(StackSlotKind::ExplicitSlot, 20));
+        let block0 = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        let zero = pos.ins().iconst(types::I64, 0);
+        pos.ins().stack_store(zero, ss0, 0);
+        pos.ins().return_(&[]);
+
+        let mut context = Context::for_function(func);
+        let code = context
+            .compile(&*isa, &mut Default::default())
+            .expect("expected compilation");
+
+        // The 20-byte slot is rounded up to a whole number of words, and the
+        // frame is then padded to 16 bytes. Nothing is spilled.
+        let sizes = code.frame_sizes;
+        assert_eq!(sizes.setup_area, 16);
+        assert_eq!(sizes.clobbers, 0);
+        assert_eq!(sizes.stack_slots, 24);
+        assert_eq!(sizes.spill_slots, 0);
+        assert_eq!(sizes.padding, 8);
+        assert_eq!(sizes.outgoing_args, 0);
+        assert_eq!(code.frame_size, 32);
+    }
+
+    #[test]
+    #[cfg(feature = "x86")]
+    fn frame_sizes_with_spills() {
+        use crate::cursor::{Cursor, FuncCursor};
+        use crate::ir::{
+            types, AbiParam, ExtFuncData, ExternalName, Function, InstBuilder, MemFlags, Signature,
+            UserExternalName, UserFuncName,
+        };
+        use crate::isa::{lookup, CallConv};
+        use crate::settings::{builder, Flags};
+        use crate::Context;
+        use target_lexicon::triple;
+
+        let isa = lookup(triple!("x86_64"))
+            .expect("expect x86 ISA")
+            .finish(Flags::new(builder()))
+            .expect("expect backend creation to succeed");
+
+        // Load ten values, keep them live across a call, and add them up. There are only five
+        // callee-saved registers, so at least five values have to be spilled around the call.
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params.push(AbiParam::new(types::I64));
+        sig.returns.push(AbiParam::new(types::I64));
+        let mut func = Function::with_name_signature(UserFuncName::default(), sig);
+        let callee_sig = func.import_signature(Signature::new(CallConv::SystemV));
+        let callee_name = func.declare_imported_user_function(UserExternalName::new(0, 0));
+        let callee = func.import_function(ExtFuncData {
+            name: ExternalName::User(callee_name),
+            signature: callee_sig,
+            colocated: false,
+        });
+        let block0 = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block0);
+        let addr = pos.func.dfg.append_block_param(block0, types::I64);
+        let values: alloc::vec::Vec<_> = (0..10)
+            .map(|i| pos.ins().load(types::I64, MemFlags::trusted(), addr, i * 8))
+            .collect();
+        pos.ins().call(callee, &[]);
+        let sum = values[1..]
+            .iter()
+            .fold(values[0], |sum, &value| pos.ins().iadd(sum, value));
+        pos.ins().return_(&[sum]);
+
+        let mut context = Context::for_function(func);
+        let code = context
+            .compile(&*isa, &mut Default::default())
+            .expect("expected compilation");
+
+        let sizes = code.frame_sizes;
+        assert_eq!(sizes.stack_slots, 0);
+        assert!(sizes.spill_slots >= 5 * 8, "{sizes:?}");
+        assert_eq!(sizes.spill_slots % 8, 0);
+        assert!(sizes.padding < 16);
+        assert_eq!((sizes.spill_slots + sizes.padding) % 16, 0);
+        assert_eq!(
+            code.frame_size,
+            sizes.clobbers + sizes.spill_slots + sizes.padding
+        );
+    }
 }
@@ -322,6 +322,8 @@ pub struct CompiledCodeBase<T: CompilePhase> {
     pub buffer: MachBufferFinalized<T>,
     /// Size of stack frame, in bytes.
     pub frame_size: u32,
+    /// Sizes of the areas of the stack frame, in bytes.
+    pub frame_sizes: FrameSizes,
     /// Disassembly, if requested.
     pub vcode: Option<String>,
     /// Debug info: value labels to registers/stackslots at code offsets.
@@ -342,6 +344,12 @@ pub struct CompiledCodeBase<T: CompilePhase> {
     /// This info is generated only if the `machine_code_cfg_info`
     /// flag is set.
     pub bb_edges: Vec<(CodeOffset, CodeOffset)>,
+    /// ABI info: for each parameter of the function's signature, the registers or incoming
+    /// stack slots it arrives in.
+    pub param_locations: Vec<ArgLocations>,
+    /// ABI info: for each return value of the function's signature, the registers or
+    /// return-area slots it leaves the function in.
+    pub return_locations: Vec<ArgLocations>,
 }
 
 impl CompiledCodeStencil {
@@ -350,12 +358,15 @@ impl CompiledCodeStencil {
         CompiledCode {
             buffer: self.buffer.apply_base_srcloc(params.base_srcloc()),
             frame_size: self.frame_size,
+            frame_sizes: self.frame_sizes,
             vcode: self.vcode,
             value_labels_ranges: self.value_labels_ranges,
             sized_stackslot_offsets: self.sized_stackslot_offsets,
             dynamic_stackslot_offsets: self.dynamic_stackslot_offsets,
             bb_starts: self.bb_starts,
             bb_edges: self.bb_edges,
+            param_locations: self.param_locations,
+            return_locations: self.return_locations,
         }
     }
 }
@@ -202,6 +202,15 @@ pub struct EmitResult {
 
     /// Stack frame size.
     pub frame_size: u32,
+
+    /// Sizes of the areas of the stack frame.
+    pub frame_sizes: FrameSizes,
+
+    /// ABI locations of the function's parameters.
+    pub param_locations: Vec<ArgLocations>,
+
+    /// ABI locations of the function's return values.
+    pub return_locations: Vec<ArgLocations>,
 }
 
 /// A builder for a VCode function body.
@@ -1059,6 +1068,9 @@ impl<I: VCodeInst> VCode<I> {
             dynamic_stackslot_offsets: self.abi.dynamic_stackslot_offsets().clone(),
             value_labels_ranges,
             frame_size,
+            frame_sizes: self.abi.frame_sizes(),
+            param_locations: self.abi.param_locations(&self.sigs),
+            return_locations: self.abi.return_locations(&self.sigs),
         }
     }
 
@@ -12,6 +12,7 @@ pub use crate::ir::{
 };
 use crate::isle_common_prelude_methods;
 use crate::machinst::isle::*;
+use crate::settings::FloatMode;
 use crate::trace;
 use cranelift_entity::packed_option::ReservedValue;
 use smallvec::{smallvec, SmallVec};
@@ -245,6 +246,18 @@ impl<'a, 'b, 'c> generated_code::Context for IsleContext<'a, 'b, 'c> {
         value
     }
 
+    fn fma_contraction_enabled(&mut self) -> bool {
+        self.ctx.flags.enable_fma_contraction() || self.float_mode_relaxed()
+    }
+
+    fn has_native_fma(&mut self) -> bool {
+        self.ctx.isa.map_or(false, |isa| isa.has_native_fma())
+    }
+
+    fn float_mode_relaxed(&mut self) -> bool {
+        self.ctx.flags.float_mode() == FloatMode::Relaxed
+    }
+
     fn splat64(&mut self, val: u64) -> Constant {
         let val = u128::from(val);
         let val = val | (val << 64);
@@ -31,6 +31,10 @@ pub enum CodegenError {
     /// is exceeded, compilation fails.
     CodeTooLarge,
 
+    /// The memory used by the IR and analyses of the function exceeded the limit set with
+    /// `Context::set_memory_limit`.
+    MemoryLimitExceeded,
+
     /// Something is not supported by the code generator. This might be an indication that a
     /// feature is used without explicitly enabling it, or that something is temporarily
     /// unsupported by a given target backend.
@@ -58,6 +62,7 @@ impl std::error::Error for CodegenError {
             CodegenError::Verifier(source) => Some(source),
             CodegenError::ImplLimitExceeded { .. }
             | CodegenError::CodeTooLarge { .. }
+            | CodegenError::MemoryLimitExceeded { .. }
             | CodegenError::Unsupported { .. } => None,
             #[cfg(feature = "unwind")]
             CodegenError::RegisterMappingError { .. } => None,
@@ -73,6 +78,9 @@ impl std::fmt::Display for CodegenError {
             CodegenError::Verifier(_) => write!(f, "Verifier errors"),
             CodegenError::ImplLimitExceeded => write!(f, "Implementation limit exceeded"),
             CodegenError::CodeTooLarge => write!(f, "Code for function is too large"),
+            CodegenError::MemoryLimitExceeded => {
+                write!(f, "Memory limit exceeded while compiling function")
+            }
             CodegenError::Unsupported(feature) => write!(f, "Unsupported feature: {}", feature),
             #[cfg(feature = "unwind")]
             CodegenError::RegisterMappingError(_0) => write!(f, "Register mapping error"),
@@ -510,20 +510,24 @@ mod tests {
         let actual = f.to_string();
         let expected = r#"[shared]
 opt_level = "none"
+float_mode = "strict"
 tls_model = "none"
 libcall_call_conv = "isa_default"
 probestack_size_log2 = 12
 probestack_strategy = "outline"
 bb_padding_log2_minus_one = 0
+max_insts_log2 = 0
 regalloc_checker = false
 regalloc_verbose_logs = false
 enable_alias_analysis = true
 enable_verifier = true
+reject_irreducible_control_flow = false
 enable_pcc = false
 is_pic = false
 use_colocated_libcalls = false
 enable_float = true
 enable_nan_canonicalization = false
+enable_fma_contraction = false
 enable_pinned_reg = false
 enable_atomics = true
 enable_safepoints = false
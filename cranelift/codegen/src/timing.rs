@@ -8,6 +8,7 @@ use std::boxed::Box;
 use std::cell::RefCell;
 use std::mem;
 use std::time::Duration;
+use std::vec::Vec;
 
 // Each pass that can be timed is predefined with the `define_passes!` macro. Each pass has a
 // snake_case name and a plain text description used when printing out the timing report.
@@ -61,12 +62,14 @@ define_passes! {
     flowgraph: "Control flow graph",
     domtree: "Dominator tree",
     loop_analysis: "Loop analysis",
+    use_lists: "Use lists",
     preopt: "Pre-legalization rewriting",
     egraph: "Egraph based optimizations",
     gvn: "Global value numbering",
     licm: "Loop invariant code motion",
     unreachable_code: "Remove unreachable blocks",
     remove_constant_phis: "Remove constant phi-nodes",
+    legalize: "Legalization",
 
     vcode_lower: "VCode lowering",
     vcode_emit: "VCode emission",
@@ -77,6 +80,7 @@ define_passes! {
     layout_renumber: "Layout full renumbering",
 
     canonicalize_nans: "Canonicalization of NaNs",
+    coverage_instrumentation: "Coverage instrumentation",
 }
 
 impl Pass {
@@ -124,9 +128,14 @@ pub fn set_thread_profiler(new_profiler: Box<dyn Profiler>) -> Box<dyn Profiler>
 
 /// Start timing `pass` as a child of the currently running pass, if any.
 ///
-/// This function is called by the publicly exposed pass functions.
+/// This function is called by the publicly exposed pass functions. With the `tracing-spans`
+/// feature, the pass also runs inside a `tracing` span, which is exited after the profiler's
+/// token is dropped.
 fn start_pass(pass: Pass) -> Box<dyn Any> {
-    PROFILER.with(|profiler| profiler.borrow().start_pass(pass))
+    let token = PROFILER.with(|profiler| profiler.borrow().start_pass(pass));
+    #[cfg(feature = "tracing-spans")]
+    let token = Box::new((token, tracing::debug_span!("pass", pass = ?pass).entered()));
+    token
 }
 
 /// Accumulated timing information for a single pass.
@@ -157,6 +166,19 @@ impl PassTimes {
     pub fn total(&self) -> Duration {
         self.pass.iter().map(|p| p.total - p.child).sum()
     }
+
+    /// Returns the description and self time of every pass that ran, slowest first.
+    pub fn by_self_time(&self) -> Vec<(&'static str, Duration)> {
+        let mut passes: Vec<_> = self
+            .pass
+            .iter()
+            .zip(&DESCRIPTIONS[..])
+            .filter(|(time, _)| time.total != Duration::default())
+            .map(|(time, &desc)| (desc, time.total.saturating_sub(time.child)))
+            .collect();
+        passes.sort_by(|a, b| b.1.cmp(&a.1));
+        passes
+    }
 }
 
 impl Default for PassTimes {
@@ -293,4 +315,29 @@ mod tests {
         assert_eq!(Pass::None.to_string(), "<no pass>");
         assert_eq!(Pass::regalloc.to_string(), "Register allocation");
     }
+
+    #[test]
+    fn by_self_time() {
+        let mut times = PassTimes::default();
+        times.pass[Pass::compile.idx()] = PassTime {
+            total: Duration::from_millis(10),
+            child: Duration::from_millis(8),
+        };
+        times.pass[Pass::regalloc.idx()] = PassTime {
+            total: Duration::from_millis(5),
+            child: Duration::default(),
+        };
+        times.pass[Pass::vcode_lower.idx()] = PassTime {
+            total: Duration::from_millis(3),
+            child: Duration::default(),
+        };
+        assert_eq!(
+            times.by_self_time(),
+            [
+                ("Register allocation", Duration::from_millis(5)),
+                ("VCode lowering", Duration::from_millis(3)),
+                ("Compilation passes", Duration::from_millis(2)),
+            ]
+        );
+    }
 }
@@ -0,0 +1,170 @@
+//! Def-use information: the instructions that use each value.
+//!
+//! The data flow graph only records, for each instruction, the values it uses. Finding the users
+//! of a value would otherwise take a scan over the whole function, so passes that need to ask
+//! "who uses v7?" repeatedly can compute a `UseLists` once instead.
+//!
+//! Like the control flow graph and dominator tree, use lists are not kept up to date as the
+//! function is mutated. A pass that changes the function either recomputes them or calls
+//! `UseLists::clear` to invalidate them in bulk.
+
+use crate::entity::SecondaryMap;
+use crate::ir::{Function, Inst, Value};
+use crate::timing;
+use alloc::vec::Vec;
+
+/// The range of `UseLists::uses` holding the users of one value.
+#[derive(Clone, Copy, Default)]
+struct UseRange {
+    start: u32,
+    len: u32,
+}
+
+/// The instructions in the layout that use each value of a function.
+pub struct UseLists {
+    ranges: SecondaryMap<Value, UseRange>,
+    uses: Vec<Inst>,
+    valid: bool,
+}
+
+impl UseLists {
+    /// Allocate a new blank use list set. Use `compute` to compute the use lists for a function.
+    pub fn new() -> Self {
+        Self {
+            ranges: SecondaryMap::new(),
+            uses: Vec::new(),
+            valid: false,
+        }
+    }
+
+    /// Allocate and compute the use lists for `func`.
+    pub fn with_function(func: &Function) -> Self {
+        let mut use_lists = Self::new();
+        use_lists.compute(func);
+        use_lists
+    }
+
+    /// Compute the use lists of `func`.
+    ///
+    /// This will clear and overwrite any information already stored in this data structure.
+    pub fn compute(&mut self, func: &Function) {
+        let _tt = timing::use_lists();
+        self.clear();
+        self.ranges.resize(func.dfg.num_values());
+
+        // Count the uses of each value, then turn the counts into ranges and fill them in.
+        for block in func.layout.blocks() {
+            for inst in func.layout.block_insts(block) {
+                for arg in func.dfg.inst_values(inst) {
+                    self.ranges[func.dfg.resolve_aliases(arg)].len += 1;
+                }
+            }
+        }
+
+        let mut start = 0;
+        for range in self.ranges.values_mut() {
+            range.start = start;
+            start += range.len;
+            range.len = 0;
+        }
+        self.uses.resize(start as usize, Inst::from_u32(0));
+
+        for block in func.layout.blocks() {
+            for inst in func.layout.block_insts(block) {
+                for arg in func.dfg.inst_values(inst) {
+                    let range = &mut self.ranges[func.dfg.resolve_aliases(arg)];
+                    self.uses[(range.start + range.len) as usize] = inst;
+                    range.len += 1;
+                }
+            }
+        }
+
+        self.valid = true;
+    }
+
+    /// Clear the use lists, invalidating them.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+        self.uses.clear();
+        self.valid = false;
+    }
+
+    /// Check if the use lists are in a valid state.
+    ///
+    /// Note that this doesn't perform any kind of validity checks. It simply checks if the
+    /// `compute()` method has been called since the last `clear()`. It does not check that the
+    /// use lists are consistent with the function.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Get the instructions that use `v`, in layout order.
+    ///
+    /// An instruction appears once for every operand that uses `v`, including block call
+    /// arguments. Uses of aliases are recorded under the value they resolve to, so `v` should not
+    /// itself be an alias.
+    pub fn uses(&self, v: Value) -> &[Inst] {
+        debug_assert!(self.valid);
+        let range = self.ranges[v];
+        &self.uses[range.start as usize..][..range.len as usize]
+    }
+
+    /// Check if `v` has any uses.
+    pub fn has_uses(&self, v: Value) -> bool {
+        !self.uses(v).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cursor::{Cursor, FuncCursor};
+    use crate::ir::{types, InstBuilder};
+
+    #[test]
+    fn empty() {
+        let func = Function::new();
+        let use_lists = UseLists::with_function(&func);
+        assert!(use_lists.is_valid());
+    }
+
+    #[test]
+    fn uses() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        let block1 = func.dfg.make_block();
+        let v0 = func.dfg.append_block_param(block0, types::I32);
+        let v3 = func.dfg.append_block_param(block1, types::I32);
+
+        let (v1, v2, jump, ret);
+        {
+            let mut cur = FuncCursor::new(&mut func);
+
+            cur.insert_block(block0);
+            v1 = cur.ins().iadd(v0, v0);
+            v2 = cur.ins().imul(v1, v0);
+            jump = cur.ins().jump(block1, &[v2]);
+
+            cur.insert_block(block1);
+            ret = cur.ins().return_(&[v3]);
+        }
+        let add = func.dfg.value_def(v1).unwrap_inst();
+        let mul = func.dfg.value_def(v2).unwrap_inst();
+
+        let mut use_lists = UseLists::with_function(&func);
+        assert_eq!(use_lists.uses(v0), [add, add, mul]);
+        assert_eq!(use_lists.uses(v1), [mul]);
+        assert_eq!(use_lists.uses(v2), [jump]);
+        assert_eq!(use_lists.uses(v3), [ret]);
+
+        // Uses of an alias count as uses of the aliased value.
+        func.dfg.detach_block_params(block1);
+        func.dfg.change_to_alias(v3, v1);
+        use_lists.compute(&func);
+        assert_eq!(use_lists.uses(v1), [mul, ret]);
+        assert!(!use_lists.has_uses(v3));
+
+        use_lists.clear();
+        assert!(!use_lists.is_valid());
+    }
+}
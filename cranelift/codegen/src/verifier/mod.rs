@@ -28,6 +28,8 @@
 //! - All branches to a block must be present in the CFG.
 //! - A recomputed dominator tree is identical to the existing one.
 //! - The entry block must not be a cold block.
+//! - With the `reject_irreducible_control_flow` setting, every cycle in the CFG must be a
+//!   natural loop, entered only through its header.
 //!
 //! Type checking
 //!
@@ -78,7 +80,7 @@ use crate::ir::{
 use crate::isa::TargetIsa;
 use crate::iterators::IteratorExtras;
 use crate::print_errors::pretty_verifier_error;
-use crate::settings::FlagsOrIsa;
+use crate::settings::{Flags, FlagsOrIsa};
 use crate::timing;
 use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
@@ -277,6 +279,28 @@ pub fn verify_function<'a, FOI: Into<FlagsOrIsa<'a>>>(
     }
 }
 
+/// Quickly check the structural integrity of `func`.
+///
+/// This only checks that the layout is consistent and that every entity referenced by an
+/// instruction exists. Type checking and SSA dominance are skipped, so this is cheap enough to run
+/// on every function in release builds to catch corrupted IR before emission. Use
+/// [`verify_function`] for a complete check.
+pub fn validate_quick<'a, FOI: Into<FlagsOrIsa<'a>>>(
+    func: &Function,
+    fisa: FOI,
+) -> VerifierResult<()> {
+    let _tt = timing::verifier();
+    let mut errors = VerifierErrors::default();
+    let verifier = Verifier::new_quick(func, fisa.into());
+    let result = verifier.run_quick(&mut errors);
+    if errors.is_empty() {
+        result.unwrap();
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Verify `func` after checking the integrity of associated context data structures `cfg` and
 /// `domtree`.
 pub fn verify_context<'a, FOI: Into<FlagsOrIsa<'a>>>(
@@ -301,7 +325,10 @@ struct Verifier<'a> {
     func: &'a Function,
     expected_cfg: ControlFlowGraph,
     expected_domtree: DominatorTree,
+    flags: &'a Flags,
     isa: Option<&'a dyn TargetIsa>,
+    /// Skip the checks that need `expected_cfg` and `expected_domtree`, which are left empty.
+    quick: bool,
 }
 
 impl<'a> Verifier<'a> {
@@ -312,7 +339,20 @@ impl<'a> Verifier<'a> {
             func,
             expected_cfg,
             expected_domtree,
+            flags: fisa.flags,
+            isa: fisa.isa,
+            quick: false,
+        }
+    }
+
+    fn new_quick(func: &'a Function, fisa: FlagsOrIsa<'a>) -> Self {
+        Self {
+            func,
+            expected_cfg: ControlFlowGraph::new(),
+            expected_domtree: DominatorTree::new(),
+            flags: fisa.flags,
             isa: fisa.isa,
+            quick: true,
         }
     }
 
@@ -880,7 +920,7 @@ impl<'a> Verifier<'a> {
             .layout
             .inst_block(loc_inst)
             .expect("Instruction not in layout.");
-        let is_reachable = self.expected_domtree.is_reachable(loc_block);
+        let is_reachable = !self.quick && self.expected_domtree.is_reachable(loc_block);
 
         // SSA form
         match dfg.value_def(v) {
@@ -1154,6 +1194,36 @@ impl<'a> Verifier<'a> {
         errors.as_result()
     }
 
+    /// Report branches that enter a cycle at a block other than its header.
+    ///
+    /// Such an edge goes backwards in reverse postorder, like a loop's back edge, but its target
+    /// doesn't dominate its source.
+    fn reducible_control_flow(&self, errors: &mut VerifierErrors) -> VerifierStepResult {
+        if !self.flags.reject_irreducible_control_flow() {
+            return Ok(());
+        }
+        for &block in self.expected_domtree.cfg_postorder().iter().rev() {
+            for BlockPredecessor { block: pred, inst } in self.expected_cfg.pred_iter(block) {
+                if self.expected_domtree.is_reachable(pred)
+                    && self.expected_domtree.rpo_cmp_block(block, pred) != Ordering::Greater
+                    && !self
+                        .expected_domtree
+                        .dominates(block, inst, &self.func.layout)
+                {
+                    errors.report((
+                        inst,
+                        self.context(inst),
+                        format!(
+                            "irreducible control flow: {} enters a cycle at {}, which doesn't dominate {}",
+                            inst, block, pred
+                        ),
+                    ));
+                }
+            }
+        }
+        errors.as_result()
+    }
+
     fn typecheck(&self, inst: Inst, errors: &mut VerifierErrors) -> VerifierStepResult {
         let inst_data = &self.func.dfg.insts[inst];
         let constraints = inst_data.opcode().constraints();
@@ -1747,6 +1817,8 @@ impl<'a> Verifier<'a> {
             self.encodable_as_bb(block, errors)?;
         }
 
+        self.reducible_control_flow(errors)?;
+
         if !errors.is_empty() {
             log::warn!(
                 "Found verifier errors in function:\n{}",
@@ -1756,13 +1828,28 @@ impl<'a> Verifier<'a> {
 
         Ok(())
     }
+
+    fn run_quick(&self, errors: &mut VerifierErrors) -> VerifierStepResult {
+        for block in self.func.layout.blocks() {
+            if self.func.layout.first_inst(block).is_none() {
+                return errors.fatal((block, format!("{} cannot be empty", block)));
+            }
+            for inst in self.func.layout.block_insts(block) {
+                self.block_integrity(block, inst, errors)?;
+                self.instruction_integrity(inst, errors)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Verifier, VerifierError, VerifierErrors};
+    use super::{validate_quick, verify_function, Verifier, VerifierError, VerifierErrors};
+    use crate::cursor::{Cursor, FuncCursor};
     use crate::ir::instructions::{InstructionData, Opcode};
-    use crate::ir::{types, AbiParam, Function, Type};
+    use crate::ir::{types, AbiParam, Function, InstBuilder, Type};
     use crate::settings;
 
     macro_rules! assert_err_with_msg {
@@ -1819,6 +1906,50 @@ mod tests {
         assert_err_with_msg!(errors, "instruction format");
     }
 
+    #[test]
+    fn quick_skips_dominance() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        {
+            let mut cur = FuncCursor::new(&mut func);
+            cur.insert_block(block0);
+            let v0 = cur.ins().iconst(types::I32, 1);
+            cur.ins().return_(&[]);
+            // Use `v0` before its definition.
+            cur.goto_inst(cur.func.dfg.value_def(v0).unwrap_inst());
+            cur.ins().iadd(v0, v0);
+        }
+        let flags = &settings::Flags::new(settings::builder());
+
+        assert_eq!(validate_quick(&func, flags), Ok(()));
+        let errors = verify_function(&func, flags).unwrap_err();
+        assert_err_with_msg!(errors, "non-dominating");
+    }
+
+    #[test]
+    fn quick_bad_instruction_format() {
+        let mut func = Function::new();
+        let block0 = func.dfg.make_block();
+        func.layout.append_block(block0);
+        let nullary_with_bad_opcode = func.dfg.make_inst(InstructionData::UnaryImm {
+            opcode: Opcode::F32const,
+            imm: 0.into(),
+        });
+        func.layout.append_inst(nullary_with_bad_opcode, block0);
+        let destination = func.dfg.block_call(block0, &[]);
+        func.stencil.layout.append_inst(
+            func.stencil.dfg.make_inst(InstructionData::Jump {
+                opcode: Opcode::Jump,
+                destination,
+            }),
+            block0,
+        );
+        let flags = &settings::Flags::new(settings::builder());
+
+        let errors = validate_quick(&func, flags).unwrap_err();
+        assert_err_with_msg!(errors, "instruction format");
+    }
+
     fn test_iconst_bounds(immediate: i64, ctrl_typevar: Type) -> VerifierErrors {
         let mut func = Function::new();
         let block0 = func.dfg.make_block();
@@ -10,6 +10,7 @@ use crate::ir::{Block, DataFlowGraph, Function, Inst, SigRef, Type, Value, Value
 use crate::packed_option::ReservedValue;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{self, Write};
 
 /// A `FuncWriter` used to decorate functions during printing.
@@ -55,7 +56,18 @@ pub trait FuncWriter {
         for (gv, gv_data) in &func.global_values {
             any = true;
             let maybe_fact = func.global_value_facts[gv].as_ref();
-            self.write_entity_definition(w, func, gv.into(), gv_data, maybe_fact)?;
+            self.write_entity_definition(
+                w,
+                func,
+                gv.into(),
+                &gv_data.display(Some(&func.params)),
+                maybe_fact,
+            )?;
+        }
+
+        for (dt, dt_data) in &func.dfg.dynamic_types {
+            any = true;
+            self.write_entity_definition(w, func, dt.into(), dt_data, None)?;
         }
 
         for (mt, mt_data) in &func.memory_types {
@@ -248,7 +260,21 @@ pub fn write_block_header(
         write!(w, ", ")?;
         write_arg(w, func, arg)?;
     }
-    writeln!(w, "){}:", cold)
+    write!(w, "){}:", cold)?;
+    write_value_names(w, &func.dfg, func.dfg.block_params(block))?;
+    writeln!(w)
+}
+
+/// Write the names of any named `values` as a trailing comment.
+fn write_value_names(w: &mut dyn Write, dfg: &DataFlowGraph, values: &[Value]) -> fmt::Result {
+    let mut sep = " ; ";
+    for &v in values {
+        if let Some(name) = dfg.value_name(v) {
+            write!(w, "{}{}: {}", sep, v, name)?;
+            sep = ", ";
+        }
+    }
+    Ok(())
 }
 
 fn decorate_block<FW: FuncWriter>(
@@ -293,13 +319,20 @@ fn type_suffix(func: &Function, inst: Inst) -> Option<Type> {
     // If the controlling type variable can be inferred from the type of the designated value input
     // operand, we don't need the type suffix.
     if constraints.use_typevar_operand() {
+        // The parser only knows the types of values defined earlier in the text, so the operand
+        // has to be a parameter of this block or the result of an earlier instruction in it.
         let ctrl_var = inst_data.typevar_operand(&func.dfg.value_lists).unwrap();
-        let def_block = match func.dfg.value_def(ctrl_var) {
-            ValueDef::Result(instr, _) => func.layout.inst_block(instr),
-            ValueDef::Param(block, _) => Some(block),
-            ValueDef::Union(..) => None,
+        let block = func.layout.inst_block(inst);
+        let defined_before = match func.dfg.value_def(ctrl_var) {
+            ValueDef::Result(instr, _) => {
+                block.is_some()
+                    && func.layout.inst_block(instr) == block
+                    && func.layout.pp_cmp(instr, inst) == Ordering::Less
+            }
+            ValueDef::Param(param_block, _) => block == Some(param_block),
+            ValueDef::Union(..) => false,
         };
-        if def_block.is_some() && def_block == func.layout.inst_block(inst) {
+        if defined_before {
             return None;
         }
     }
@@ -370,11 +403,22 @@ fn write_instruction(
     let opcode = func.dfg.insts[inst].opcode();
 
     match type_suffix(func, inst) {
+        // Dynamic vector types are written as one of the declared dynamic types.
+        Some(suf) if suf.is_dynamic_vector() => match func
+            .dfg
+            .dynamic_types
+            .iter()
+            .find(|(_, data)| data.concrete() == Some(suf))
+        {
+            Some((dt, _)) => write!(w, "{}.{}", opcode, dt)?,
+            None => write!(w, "{}.{}", opcode, suf)?,
+        },
         Some(suf) => write!(w, "{}.{}", opcode, suf)?,
         None => write!(w, "{}", opcode)?,
     }
 
     write_operands(w, &func.dfg, inst)?;
+    write_value_names(w, &func.dfg, func.dfg.inst_results(inst))?;
     writeln!(w)?;
 
     // Value aliases come out on lines after the instruction defining the referent.
@@ -390,8 +434,12 @@ pub fn write_operands(w: &mut dyn Write, dfg: &DataFlowGraph, inst: Inst) -> fmt
     let jump_tables = &dfg.jump_tables;
     use crate::ir::instructions::InstructionData::*;
     match dfg.insts[inst] {
-        AtomicRmw { op, args, .. } => write!(w, " {} {}, {}", op, args[0], args[1]),
-        AtomicCas { args, .. } => write!(w, " {}, {}, {}", args[0], args[1], args[2]),
+        AtomicRmw {
+            flags, op, args, ..
+        } => write!(w, "{} {} {}, {}", flags, op, args[0], args[1]),
+        AtomicCas { flags, args, .. } => {
+            write!(w, "{} {}, {}, {}", flags, args[0], args[1], args[2])
+        }
         LoadNoOffset { flags, arg, .. } => write!(w, "{} {}", flags, arg),
         StoreNoOffset { flags, args, .. } => write!(w, "{} {}, {}", flags, args[0], args[1]),
         Unary { arg, .. } => write!(w, " {}", arg),
@@ -607,6 +655,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn value_names() {
+        let mut func = Function::new();
+        {
+            let block0 = func.dfg.make_block();
+            let mut pos = FuncCursor::new(&mut func);
+            pos.insert_block(block0);
+
+            let v0 = pos.func.dfg.append_block_param(block0, types::I32);
+            let v1 = pos.func.dfg.append_block_param(block0, types::I32);
+            pos.func.dfg.set_value_name(v1, "len");
+            let v2 = pos.ins().iadd(v0, v1);
+            pos.func.dfg.set_value_name(v2, "sum");
+            pos.ins().return_(&[v2]);
+        }
+        assert_eq!(
+            func.to_string(),
+            "function u0:0() fast {\nblock0(v0: i32, v1: i32): ; v1: len\n    v2 = iadd v0, v1 ; v2: sum\n    return v2\n}\n"
+        );
+    }
+
     #[test]
     fn cold_blocks() {
         let mut func = Function::new();
@@ -10,7 +10,7 @@ use core::ops::{Index, IndexMut};
 use core::slice;
 #[cfg(feature = "enable-serde")]
 use serde::{
-    de::{Deserializer, SeqAccess, Visitor},
+    de::{value::UnitDeserializer, Deserializer, SeqAccess, Visitor},
     ser::{SerializeSeq, Serializer},
     Deserialize, Serialize,
 };
@@ -259,8 +259,16 @@ where
             where
                 A: SeqAccess<'de>,
             {
-                match seq.next_element()? {
-                    Some(Some(default_val)) => {
+                // Self-describing formats such as JSON write `Some(None)` the same as `None`, so
+                // a `None` default of an `Option` value reads back as a missing default. Fall
+                // back to reading the default from a unit value, which only works for such types.
+                let default_val = match seq.next_element()? {
+                    Some(Some(default_val)) => Some(default_val),
+                    Some(None) => V::deserialize(UnitDeserializer::<A::Error>::new()).ok(),
+                    None => None,
+                };
+                match default_val {
+                    Some(default_val) => {
                         let default_val: V = default_val; // compiler can't infer the type
                         let mut m = SecondaryMap::with_default(default_val.clone());
                         let mut idx = 0;
@@ -323,4 +331,46 @@ mod tests {
         assert_eq!(shared[r1], 5);
         assert_eq!(shared[r2], 3);
     }
+
+    #[test]
+    #[cfg(feature = "enable-serde")]
+    fn serde_roundtrip() {
+        let mut m: SecondaryMap<E, u32> = SecondaryMap::with_default(7);
+        m[E(1)] = 5;
+        m[E(3)] = 7;
+        let json = serde_json::to_string(&m).unwrap();
+        // The default comes first, then each element, or `null` for the default.
+        assert_eq!(json, "[7,null,5]");
+        assert_eq!(
+            serde_json::from_str::<SecondaryMap<E, u32>>(&json).unwrap(),
+            m
+        );
+        let bytes = postcard::to_allocvec(&m).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<SecondaryMap<E, u32>>(&bytes).unwrap(),
+            m
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "enable-serde")]
+    fn serde_roundtrip_none_default() {
+        let mut m: SecondaryMap<E, Option<u32>> = SecondaryMap::new();
+        m[E(1)] = Some(0);
+        m[E(2)] = Some(3);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[null,null,0,3]");
+        assert_eq!(
+            serde_json::from_str::<SecondaryMap<E, Option<u32>>>(&json).unwrap(),
+            m
+        );
+        let bytes = postcard::to_allocvec(&m).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<SecondaryMap<E, Option<u32>>>(&bytes).unwrap(),
+            m
+        );
+
+        // A missing default is still an error.
+        assert!(serde_json::from_str::<SecondaryMap<E, Option<u32>>>("[]").is_err());
+    }
 }
@@ -39,8 +39,16 @@ mod test_verifier;
 /// Directories are scanned recursively for test cases ending in `.clif`. These test cases are
 /// executed on background threads.
 ///
-pub fn run(verbose: bool, report_times: bool, files: &[String]) -> anyhow::Result<()> {
+/// If `filter` is given, only test files whose path contains it are run.
+///
+pub fn run(
+    verbose: bool,
+    report_times: bool,
+    filter: Option<&str>,
+    files: &[String],
+) -> anyhow::Result<()> {
     let mut runner = TestRunner::new(verbose, report_times);
+    runner.set_filter(filter.map(str::to_string));
 
     for path in files.iter().map(Path::new) {
         if path.is_file() {
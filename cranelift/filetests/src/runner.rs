@@ -8,6 +8,7 @@ use crate::runone;
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fmt::{self, Display};
+use std::io::{IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::time;
 
@@ -59,6 +60,12 @@ pub struct TestRunner {
     // Should we print the timings out?
     report_times: bool,
 
+    // Only run tests whose path contains this substring.
+    filter: Option<String>,
+
+    // Should we keep a live progress line on the terminal?
+    progress: bool,
+
     // Directories that have not yet been scanned.
     dir_stack: Vec<PathBuf>,
 
@@ -86,6 +93,8 @@ impl TestRunner {
         Self {
             verbose,
             report_times,
+            filter: None,
+            progress: !verbose && std::io::stdout().is_terminal(),
             dir_stack: Vec::new(),
             tests: Vec::new(),
             new_tests: 0,
@@ -104,12 +113,24 @@ impl TestRunner {
         self.dir_stack.push(dir.into());
     }
 
+    /// Only run tests whose path contains `filter` as a substring.
+    pub fn set_filter(&mut self, filter: Option<String>) {
+        self.filter = filter;
+    }
+
     /// Add a test to be executed later.
     ///
     /// Any problems reading `file` as a test case file will be reported as a test failure.
+    /// Tests that don't match the filter set by `set_filter` are skipped.
     pub fn push_test<P: Into<PathBuf>>(&mut self, file: P) {
+        let path = file.into();
+        if let Some(filter) = &self.filter {
+            if !path.to_string_lossy().contains(filter.as_str()) {
+                return;
+            }
+        }
         self.tests.push(QueueEntry {
-            path: file.into(),
+            path,
             state: State::New,
         });
     }
@@ -180,6 +201,7 @@ impl TestRunner {
     /// Report an error related to a path.
     fn path_error<E: Error>(&mut self, path: &PathBuf, err: &E) {
         self.errors += 1;
+        self.clear_progress();
         println!("{}: {}", path.to_string_lossy(), err);
     }
 
@@ -192,6 +214,7 @@ impl TestRunner {
         }) = self.tests.get(jobid)
         {
             if self.verbose || result.is_err() {
+                self.clear_progress();
                 println!("{}", self.tests[jobid]);
             }
             true
@@ -249,6 +272,27 @@ impl TestRunner {
         while self.report_job() {
             self.reported_tests += 1;
         }
+        self.print_progress();
+    }
+
+    /// Update the live progress line, if enabled.
+    fn print_progress(&self) {
+        if self.progress {
+            print!(
+                "\r{}/{} tests, {} failed",
+                self.reported_tests,
+                self.tests.len(),
+                self.errors
+            );
+            let _ = std::io::stdout().flush();
+        }
+    }
+
+    /// Erase the live progress line so that other output starts on a clean line.
+    fn clear_progress(&self) {
+        if self.progress {
+            print!("\r\x1b[2K");
+        }
     }
 
     /// Handle a reply from the async threads.
@@ -265,6 +309,7 @@ impl TestRunner {
             Reply::Tick => {
                 self.ticks_since_progress += 1;
                 if self.ticks_since_progress == TIMEOUT_SLOW {
+                    self.clear_progress();
                     println!(
                         "STALLED for {} seconds with {}/{} tests finished",
                         self.ticks_since_progress,
@@ -298,6 +343,7 @@ impl TestRunner {
                 }
             }
             let pass_times = conc.join();
+            self.clear_progress();
             if self.report_times {
                 println!("{}", pass_times);
             }
@@ -349,6 +395,7 @@ impl TestRunner {
             } => dur > cut,
             _ => false,
         }) {
+            self.clear_progress();
             println!("slow: {}", t)
         }
     }
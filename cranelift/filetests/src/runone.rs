@@ -1,8 +1,10 @@
 //! Run the tests in a single test file.
 
+use crate::match_directive::match_directive;
 use crate::new_subtest;
 use crate::subtest::SubTest;
 use anyhow::{bail, Context as _, Result};
+use cranelift_codegen::ir::entities::AnyEntity;
 use cranelift_codegen::isa::TargetIsa;
 use cranelift_codegen::print_errors::pretty_verifier_error;
 use cranelift_codegen::settings::{Flags, FlagsOrIsa};
@@ -101,10 +103,46 @@ pub fn run(
 }
 
 // Verifies all functions in a testfile
+//
+// Errors can be expected with a `; verifier: allow <message>` annotation, which matches errors
+// containing `<message>` that are reported for the entity the annotation is attached to, or for
+// any entity when the annotation is attached to the function itself.
 fn verify_testfile(testfile: &TestFile, fisa: FlagsOrIsa) -> anyhow::Result<()> {
-    for (func, _) in &testfile.functions {
-        verify_function(func, fisa)
-            .map_err(|errors| anyhow::anyhow!("{}", pretty_verifier_error(&func, None, errors)))?;
+    for (func, details) in &testfile.functions {
+        let mut allowed = Vec::new();
+        for comment in &details.comments {
+            if let Some(tail) = match_directive(comment.text, "verifier:") {
+                match tail.strip_prefix("allow ") {
+                    Some(message) => allowed.push((comment.entity, message.trim(), false)),
+                    None => bail!("{}: unknown verifier directive: {}", func.name, tail),
+                }
+            }
+        }
+
+        if let Err(mut errors) = verify_function(func, fisa) {
+            errors.0.retain(|err| {
+                let pos = allowed.iter().position(|&(entity, message, _)| {
+                    (entity == AnyEntity::Function || entity == err.location)
+                        && err.message.contains(message)
+                });
+                if let Some(pos) = pos {
+                    allowed[pos].2 = true;
+                }
+                pos.is_none()
+            });
+            if !errors.is_empty() {
+                bail!("{}", pretty_verifier_error(&func, None, errors));
+            }
+        }
+
+        if let Some(&(entity, message, _)) = allowed.iter().find(|&&(_, _, used)| !used) {
+            bail!(
+                "{}: expected verifier error {}: {}",
+                func.name,
+                entity,
+                message
+            );
+        }
     }
 
     Ok(())
@@ -930,6 +930,11 @@ static OPCODE_SIGNATURES: Lazy<Vec<OpcodeSignature>> = Lazy::new(|| {
                 (Opcode::IaddCin),
                 (Opcode::IaddCarry),
                 (Opcode::UaddOverflowTrap),
+                (Opcode::SaddOverflowTrap),
+                (Opcode::UsubOverflowTrap),
+                (Opcode::SsubOverflowTrap),
+                (Opcode::UmulOverflowTrap),
+                (Opcode::SmulOverflowTrap),
                 (Opcode::IsubBin),
                 (Opcode::IsubBorrow),
                 (Opcode::BandImm),
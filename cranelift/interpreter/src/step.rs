@@ -747,6 +747,25 @@ where
                 assign(sum)
             }
         }
+        Opcode::SaddOverflowTrap
+        | Opcode::UsubOverflowTrap
+        | Opcode::SsubOverflowTrap
+        | Opcode::UmulOverflowTrap
+        | Opcode::SmulOverflowTrap => {
+            let (result, overflow) = match inst.opcode() {
+                Opcode::SaddOverflowTrap => arg(0).sadd_overflow(arg(1))?,
+                Opcode::UsubOverflowTrap => arg(0).usub_overflow(arg(1))?,
+                Opcode::SsubOverflowTrap => arg(0).ssub_overflow(arg(1))?,
+                Opcode::UmulOverflowTrap => arg(0).umul_overflow(arg(1))?,
+                Opcode::SmulOverflowTrap => arg(0).smul_overflow(arg(1))?,
+                _ => unreachable!(),
+            };
+            if overflow {
+                ControlFlow::Trap(CraneliftTrap::User(trap_code()))
+            } else {
+                assign(result)
+            }
+        }
         Opcode::IsubBin => choose(
             DataValueExt::into_bool(arg(2))?,
             DataValueExt::sub(
@@ -1241,6 +1260,7 @@ where
             // actually need to emit a fence here.
             ControlFlow::Continue
         }
+        Opcode::CompilerFence => ControlFlow::Continue,
         Opcode::SqmulRoundSat => {
             let lane_type = ctrl_ty.lane_type();
             let double_width = ctrl_ty.double_width().unwrap().lane_type();
@@ -0,0 +1,128 @@
+//! Compiled functions calling real host C functions, resolved by name by the
+//! JIT, to check the native calling convention against the platform ABI.
+
+use cranelift_codegen::ir::*;
+use cranelift_codegen::settings::{self, Configurable};
+use cranelift_codegen::Context;
+use cranelift_frontend::*;
+use cranelift_jit::*;
+use cranelift_module::*;
+use std::ffi::CStr;
+use std::mem;
+
+fn host_module() -> JITModule {
+    let mut flag_builder = settings::builder();
+    flag_builder.set("use_colocated_libcalls", "false").unwrap();
+    // FIXME set back to true once the x64 backend supports it.
+    flag_builder.set("is_pic", "false").unwrap();
+    let isa_builder = cranelift_native::builder().unwrap_or_else(|msg| {
+        panic!("host machine is not supported: {}", msg);
+    });
+    let isa = isa_builder
+        .finish(settings::Flags::new(flag_builder))
+        .unwrap();
+    JITModule::new(JITBuilder::with_isa(isa, default_libcall_names()))
+}
+
+/// Define an exported function with the signature `params -> returns` that
+/// forwards its arguments to the imported host function `name`, then passes
+/// the result through `finish`. Returns the address of the compiled function.
+fn define_wrapper(
+    module: &mut JITModule,
+    name: &str,
+    params: &[Type],
+    returns: &[Type],
+    finish: impl FnOnce(&mut FunctionBuilder, Value) -> Value,
+) -> *const u8 {
+    let mut sig = module.make_signature();
+    sig.params
+        .extend(params.iter().map(|&ty| AbiParam::new(ty)));
+    sig.returns
+        .extend(returns.iter().map(|&ty| AbiParam::new(ty)));
+
+    let callee = module
+        .declare_function(name, Linkage::Import, &sig)
+        .unwrap();
+    let func_id = module
+        .declare_function(&format!("call_{name}"), Linkage::Export, &sig)
+        .unwrap();
+
+    let mut ctx = Context::new();
+    ctx.func = Function::with_name_signature(UserFuncName::user(0, func_id.as_u32()), sig);
+    let mut func_ctx = FunctionBuilderContext::new();
+    {
+        let mut bcx = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let block = bcx.create_block();
+        bcx.append_block_params_for_function_params(block);
+        bcx.switch_to_block(block);
+        bcx.seal_block(block);
+
+        let args = bcx.block_params(block).to_vec();
+        let local_callee = module.declare_func_in_func(callee, &mut bcx.func);
+        let call = bcx.ins().call(local_callee, &args);
+        let result = bcx.inst_results(call)[0];
+        let result = finish(&mut bcx, result);
+        bcx.ins().return_(&[result]);
+        bcx.finalize();
+    }
+
+    module.define_function(func_id, &mut ctx).unwrap();
+    module.clear_context(&mut ctx);
+    module.finalize_definitions().unwrap();
+    module.get_finalized_function(func_id)
+}
+
+#[test]
+fn call_memcmp() {
+    let mut module = host_module();
+    let ptr = module.target_config().pointer_type();
+    let code = define_wrapper(
+        &mut module,
+        "memcmp",
+        &[ptr, ptr, ptr],
+        &[types::I32],
+        |_, result| result,
+    );
+    let memcmp: extern "C" fn(*const u8, *const u8, usize) -> i32 = unsafe { mem::transmute(code) };
+
+    let (a, b) = (b"cranelift", b"cranelimp");
+    assert_eq!(memcmp(a.as_ptr(), b.as_ptr(), 7), 0);
+    assert!(memcmp(a.as_ptr(), b.as_ptr(), 9) < 0);
+    assert!(memcmp(b.as_ptr(), a.as_ptr(), 9) > 0);
+}
+
+#[test]
+fn call_strlen() {
+    let mut module = host_module();
+    let ptr = module.target_config().pointer_type();
+    let code = define_wrapper(&mut module, "strlen", &[ptr], &[ptr], |_, result| result);
+    let strlen: extern "C" fn(*const u8) -> usize = unsafe { mem::transmute(code) };
+
+    let text = CStr::from_bytes_with_nul(b"host abi\0").unwrap();
+    assert_eq!(strlen(text.as_ptr().cast()), 8);
+}
+
+#[test]
+fn call_strtod() {
+    let mut module = host_module();
+    let ptr = module.target_config().pointer_type();
+    // Double the parsed value, so the float return register is also used
+    // after the call.
+    let code = define_wrapper(
+        &mut module,
+        "strtod",
+        &[ptr, ptr],
+        &[types::F64],
+        |bcx, result| {
+            let two = bcx.ins().f64const(2.0);
+            bcx.ins().fmul(result, two)
+        },
+    );
+    let strtod: extern "C" fn(*const u8, *mut *const u8) -> f64 = unsafe { mem::transmute(code) };
+
+    let text = CStr::from_bytes_with_nul(b"1.25e2 rest\0").unwrap();
+    let mut end = std::ptr::null();
+    assert_eq!(strtod(text.as_ptr().cast(), &mut end), 250.0);
+    assert_eq!(end, text.as_ptr().cast::<u8>().wrapping_add(6));
+    assert_eq!(strtod(text.as_ptr().cast(), std::ptr::null_mut()), 250.0);
+}
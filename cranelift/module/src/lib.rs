@@ -49,6 +49,9 @@ pub fn default_libcall_names() -> Box<dyn Fn(ir::LibCall) -> String + Send + Syn
         ir::LibCall::NearestF64 => "nearbyint".to_owned(),
         ir::LibCall::FmaF32 => "fmaf".to_owned(),
         ir::LibCall::FmaF64 => "fma".to_owned(),
+        ir::LibCall::PromoteF16ToF32 => "__extendhfsf2".to_owned(),
+        ir::LibCall::DemoteF32ToF16 => "__truncsfhf2".to_owned(),
+        ir::LibCall::DemoteF64ToF16 => "__truncdfhf2".to_owned(),
         ir::LibCall::Memcpy => "memcpy".to_owned(),
         ir::LibCall::Memset => "memset".to_owned(),
         ir::LibCall::Memmove => "memmove".to_owned(),
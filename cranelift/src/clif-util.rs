@@ -42,6 +42,16 @@ struct TestOptions {
     #[arg(short = 'T')]
     time_passes: bool,
 
+    /// Only run test files whose path contains this substring
+    #[arg(long)]
+    filter: Option<String>,
+
+    /// Rewrite the expected output of failing `precise-output` tests with the
+    /// actual output, same as setting `CRANELIFT_TEST_BLESS=1`. Tests checked
+    /// with filecheck directives such as `check:` are not rewritten
+    #[arg(long)]
+    bless: bool,
+
     /// Specify an input file to be used. Use '-' for stdin.
     #[arg(required = true)]
     files: Vec<PathBuf>,
@@ -93,9 +103,13 @@ fn main() -> anyhow::Result<()> {
         ),
 
         Commands::Test(t) => {
+            if t.bless {
+                std::env::set_var("CRANELIFT_TEST_BLESS", "1");
+            }
             cranelift_filetests::run(
                 t.verbose,
                 t.time_passes,
+                t.filter.as_deref(),
                 &t.files
                     .iter()
                     .map(|f| f.display().to_string())
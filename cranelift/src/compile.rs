@@ -3,16 +3,31 @@
 use crate::disasm::print_all;
 use crate::utils::read_to_string;
 use anyhow::{Context as _, Result};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use cranelift_codegen::ir::{
+    Function, RelSourceLoc, ValueLabel, ValueLabelAssignments, ValueLabelStart,
+};
 use cranelift_codegen::print_errors::pretty_error;
 use cranelift_codegen::settings::FlagsOrIsa;
 use cranelift_codegen::timing;
-use cranelift_codegen::Context;
+use cranelift_codegen::{CompiledCode, Context, LabelValueLoc};
 use cranelift_reader::OwnedFlagsOrIsa;
 use cranelift_reader::{parse_sets_and_triple, parse_test, ParseOptions};
+use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+/// The kind of output produced by the compiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    /// A relocatable object file. Requires `--output`.
+    Obj,
+    /// The raw machine code of every function, concatenated.
+    Bin,
+    /// Cranelift's pseudo-assembly listing of every function.
+    Asm,
+}
+
 /// Compiles Cranelift IR into target language
 #[derive(Parser)]
 pub struct Options {
@@ -24,10 +39,18 @@ pub struct Options {
     #[arg(short = 'T')]
     report_times: bool,
 
+    /// With `-T`, also report the compile time of each function, slowest first
+    #[arg(long, requires = "report_times")]
+    per_function: bool,
+
     /// Print machine code disassembly
     #[arg(short = 'D', long)]
     disasm: bool,
 
+    /// Print the stack frame layout of each function
+    #[arg(long)]
+    frame_layout: bool,
+
     /// Configure Cranelift settings
     #[arg(long = "set")]
     settings: Vec<String>,
@@ -39,16 +62,31 @@ pub struct Options {
     /// Specify an input file to be used. Use '-' for stdin.
     files: Vec<PathBuf>,
 
-    /// Output object file
+    /// Output file. Machine code and assembly are written to stdout if this is not given.
     #[arg(short = 'o', long = "output")]
     output: Option<PathBuf>,
+
+    /// Kind of output to produce. Defaults to an object file, which is only written if
+    /// `--output` is given.
+    #[arg(long, value_enum)]
+    emit: Option<Emit>,
+}
+
+impl Options {
+    fn emit(&self) -> Emit {
+        self.emit.unwrap_or(Emit::Obj)
+    }
 }
 
 pub fn run(options: &Options) -> Result<()> {
+    if options.emit == Some(Emit::Obj) && options.output.is_none() {
+        anyhow::bail!("--emit obj requires --output");
+    }
+
     let parsed = parse_sets_and_triple(&options.settings, &options.target)?;
 
-    let mut module = match (&options.output, &parsed) {
-        (Some(output), OwnedFlagsOrIsa::Isa(isa)) => {
+    let mut module = match (options.emit(), &options.output, &parsed) {
+        (Emit::Obj, Some(output), OwnedFlagsOrIsa::Isa(isa)) => {
             let builder = cranelift_object::ObjectBuilder::new(
                 isa.clone(),
                 output
@@ -62,14 +100,30 @@ pub fn run(options: &Options) -> Result<()> {
         _ => None,
     };
 
+    let mut emitted = vec![];
     for path in &options.files {
         let name = String::from(path.as_os_str().to_string_lossy());
-        handle_module(options, path, &name, parsed.as_fisa(), module.as_mut())?;
+        handle_module(
+            options,
+            path,
+            &name,
+            parsed.as_fisa(),
+            module.as_mut(),
+            &mut emitted,
+        )?;
     }
 
-    if let (Some(module), Some(output)) = (module, &options.output) {
-        let bytes = module.finish().emit()?;
-        std::fs::write(output, bytes)?;
+    match options.emit() {
+        Emit::Obj => {
+            if let (Some(module), Some(output)) = (module, &options.output) {
+                let bytes = module.finish().emit()?;
+                std::fs::write(output, bytes)?;
+            }
+        }
+        Emit::Bin | Emit::Asm => match &options.output {
+            Some(output) => std::fs::write(output, emitted)?,
+            None => std::io::stdout().write_all(&emitted)?,
+        },
     }
 
     Ok(())
@@ -81,6 +135,7 @@ fn handle_module(
     name: &str,
     fisa: FlagsOrIsa,
     module: Option<&mut impl cranelift_module::Module>,
+    emitted: &mut Vec<u8>,
 ) -> Result<()> {
     let buffer = read_to_string(&path)?;
     let test_file = parse_test(&buffer, ParseOptions::default())
@@ -95,9 +150,16 @@ fn handle_module(
         Some(isa) => isa,
     };
 
+    let mut total_times = timing::PassTimes::default();
+    let mut function_times = vec![];
+
     for (func, _) in test_file.functions {
         let mut context = Context::new();
         context.func = func;
+        context.set_disasm(options.emit() == Emit::Asm);
+        if options.frame_layout {
+            label_values(&mut context.func);
+        }
         let mut mem = vec![];
 
         // Compile and encode the result to machine code.
@@ -124,6 +186,24 @@ fn handle_module(
             println!("{}", context.func.display());
         }
 
+        match options.emit() {
+            Emit::Obj => {}
+            Emit::Bin => emitted.extend_from_slice(&mem),
+            Emit::Asm => {
+                writeln!(emitted, "function {}:", context.func.name)?;
+                emitted.extend_from_slice(
+                    context
+                        .compiled_code()
+                        .unwrap()
+                        .vcode
+                        .as_deref()
+                        .unwrap_or("")
+                        .as_bytes(),
+                );
+                writeln!(emitted)?;
+            }
+        }
+
         if options.disasm {
             let result = context.compiled_code().unwrap();
             print_all(
@@ -137,11 +217,102 @@ fn handle_module(
                 result.buffer.stack_maps(),
             )?;
         }
+
+        if options.frame_layout {
+            print_frame_layout(&context.func, context.compiled_code().unwrap());
+        }
+
+        if options.per_function {
+            let times = timing::take_current();
+            total_times.add(&times);
+            function_times.push((context.func.name.to_string(), times));
+        }
     }
 
     if options.report_times {
-        print!("{}", timing::take_current());
+        if options.per_function {
+            print_function_times(&mut function_times);
+        } else {
+            total_times = timing::take_current();
+        }
+        print!("{}", total_times);
     }
 
     Ok(())
 }
+
+/// Give every value of `func` a value label numbered after the value, so that the compiled code
+/// reports where each value lives, including the spill slots it is stored in.
+///
+/// Values created by the optimizer have no labels and aren't reported.
+fn label_values(func: &mut Function) {
+    func.collect_debug_info();
+    let values: Vec<_> = func.dfg.values().collect();
+    let values_labels = func.dfg.values_labels.as_mut().unwrap();
+    for value in values {
+        let start = ValueLabelStart {
+            from: RelSourceLoc::default(),
+            label: ValueLabel::from_u32(value.as_u32()),
+        };
+        values_labels.insert(value, ValueLabelAssignments::Starts(vec![start]));
+    }
+}
+
+/// Print the sizes of the areas of a function's stack frame, where each of its stack slots
+/// ended up, and which values were spilled where.
+fn print_frame_layout(func: &Function, compiled: &CompiledCode) {
+    let sizes = &compiled.frame_sizes;
+    println!("function {}: frame size {}", func.name, compiled.frame_size);
+    println!("  setup area     {:6}", sizes.setup_area);
+    println!("  clobbers       {:6}", sizes.clobbers);
+    println!("  stack slots    {:6}", sizes.stack_slots);
+    for (slot, &offset) in compiled.sized_stackslot_offsets.iter() {
+        let size = func.sized_stack_slots[slot].size;
+        println!("    {:<12} {:6} at sp+{}", slot.to_string(), size, offset);
+    }
+    println!("  spill slots    {:6}", sizes.spill_slots);
+    // Spill locations are reported relative to the CFA, which is the caller's SP. Rebase them
+    // on the SP that the stack slot offsets are relative to.
+    let cfa_to_sp = i64::from(compiled.frame_size + sizes.setup_area);
+    let mut spills: Vec<_> = compiled
+        .value_labels_ranges
+        .iter()
+        .flat_map(|(&label, ranges)| {
+            ranges.iter().filter_map(move |range| match range.loc {
+                LabelValueLoc::CFAOffset(offset) => Some((label.as_u32(), offset + cfa_to_sp)),
+                LabelValueLoc::Reg(_) => None,
+            })
+        })
+        .collect();
+    spills.sort_unstable();
+    spills.dedup();
+    for (value, offset) in spills {
+        println!("    v{:<11} at sp+{}", value, offset);
+    }
+    println!("  padding        {:6}", sizes.padding);
+    println!("  outgoing args  {:6}", sizes.outgoing_args);
+}
+
+/// Number of slowest passes listed for each function.
+const PASSES_PER_FUNCTION: usize = 3;
+
+/// Print the compile time of each function, slowest first, along with the passes it spent the
+/// most time in.
+fn print_function_times(function_times: &mut [(String, timing::PassTimes)]) {
+    function_times.sort_by(|a, b| b.1.total().cmp(&a.1.total()));
+
+    println!("======== ==================================");
+    println!("   Total  Function: slowest passes");
+    println!("-------- ----------------------------------");
+    for (name, times) in function_times.iter() {
+        let passes = times
+            .by_self_time()
+            .into_iter()
+            .take(PASSES_PER_FUNCTION)
+            .map(|(desc, time)| format!("{} {:.3}", desc, time.as_secs_f64()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("{:8.3}  {}: {}", times.total().as_secs_f64(), name, passes);
+    }
+    println!("======== ==================================");
+}
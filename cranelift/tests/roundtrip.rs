@@ -0,0 +1,281 @@
+//! Check that the parser and the function printer agree with each other.
+//!
+//! Every function in the filetests is parsed, printed, and parsed again, and the two parsed
+//! functions must be structurally equal. Anything that the printer doesn't write out, or that the
+//! parser doesn't read back, shows up as a difference.
+
+use anyhow::{bail, ensure, Context, Result};
+use cranelift_codegen::ir::{ExternalName, Function, GlobalValueData, Inst};
+use cranelift_reader::{parse_functions, parse_test, ParseOptions};
+use std::fs;
+use walkdir::WalkDir;
+
+#[test]
+fn parse_print_parse() -> Result<()> {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for dir in ["filetests", "docs"] {
+        for entry in WalkDir::new(dir) {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().map_or(true, |ext| ext != "clif") {
+                continue;
+            }
+
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let testfile = match parse_test(&text, ParseOptions::default()) {
+                Ok(testfile) => testfile,
+                // Like the filetest runner, skip files for targets that aren't supported.
+                Err(e) if e.is_warning => continue,
+                Err(e) => bail!("failed to parse {}: {}", path.display(), e),
+            };
+
+            for (func, _) in &testfile.functions {
+                checked += 1;
+                if let Err(e) = check_roundtrip(func) {
+                    failures.push(format!("{}: {}: {:#}", path.display(), func.name, e));
+                }
+            }
+        }
+    }
+
+    ensure!(checked > 0, "no functions found");
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} functions changed after printing and parsing again:\n{}",
+            failures.len(),
+            checked,
+            failures.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// Print `func`, parse the result, and compare it to `func`.
+fn check_roundtrip(func: &Function) -> Result<()> {
+    let printed = func.display().to_string();
+    let mut reparsed =
+        parse_functions(&printed).with_context(|| format!("failed to parse:\n{printed}"))?;
+    ensure!(
+        reparsed.len() == 1,
+        "printed text contains {} functions",
+        reparsed.len()
+    );
+    compare_functions(func, &reparsed.pop().unwrap())
+        .with_context(|| format!("printed as:\n{printed}"))
+}
+
+/// Check that `a` and `b` are structurally equal.
+///
+/// Entity references must match exactly, since the printer uses the same entity numbers as the
+/// function. The value list pools, the tables of external names and the constant pool are
+/// allowed to differ in layout, so the comparison looks through them.
+fn compare_functions(a: &Function, b: &Function) -> Result<()> {
+    ensure!(a.name == b.name, "name: {} != {}", a.name, b.name);
+    ensure!(
+        a.signature == b.signature,
+        "signature: {} != {}",
+        a.signature,
+        b.signature
+    );
+    ensure!(
+        a.sized_stack_slots == b.sized_stack_slots,
+        "sized stack slots differ"
+    );
+    ensure!(
+        a.dynamic_stack_slots == b.dynamic_stack_slots,
+        "dynamic stack slots differ"
+    );
+    ensure!(
+        a.dfg.dynamic_types == b.dfg.dynamic_types,
+        "dynamic types differ"
+    );
+    ensure!(a.memory_types == b.memory_types, "memory types differ");
+    ensure!(a.stack_limit == b.stack_limit, "stack limits differ");
+
+    ensure!(
+        a.global_values.len() == b.global_values.len(),
+        "number of global values differs"
+    );
+    for (gv, data) in &a.global_values {
+        ensure!(
+            same_global_value(a, data, b, &b.global_values[gv]),
+            "{gv}: {} != {}",
+            data,
+            b.global_values[gv]
+        );
+        ensure!(
+            a.global_value_facts[gv] == b.global_value_facts[gv],
+            "{gv}: facts differ"
+        );
+    }
+
+    ensure!(a.dfg.signatures == b.dfg.signatures, "signatures differ");
+    ensure!(
+        a.dfg.ext_funcs.len() == b.dfg.ext_funcs.len(),
+        "number of function references differs"
+    );
+    for (fnref, data) in &a.dfg.ext_funcs {
+        let other = &b.dfg.ext_funcs[fnref];
+        ensure!(
+            same_name(a, &data.name, b, &other.name)
+                && data.signature == other.signature
+                && data.colocated == other.colocated,
+            "{fnref}: {} != {}",
+            data.display(Some(&a.params)),
+            other.display(Some(&b.params))
+        );
+    }
+
+    ensure!(
+        a.dfg.constants.len() == b.dfg.constants.len(),
+        "number of constants differs"
+    );
+    for (&constant, data) in a.dfg.constants.iter() {
+        ensure!(
+            data == b.dfg.constants.get(constant),
+            "{constant}: {} != {}",
+            data,
+            b.dfg.constants.get(constant)
+        );
+    }
+
+    ensure!(
+        a.dfg.values().count() == b.dfg.values().count(),
+        "number of values differs"
+    );
+    for v in a.dfg.values() {
+        ensure!(b.dfg.value_is_valid(v), "{v} is missing");
+        ensure!(
+            a.dfg.value_type(v) == b.dfg.value_type(v),
+            "{v}: type {} != {}",
+            a.dfg.value_type(v),
+            b.dfg.value_type(v)
+        );
+        let (dest_a, dest_b) = (
+            a.dfg.value_alias_dest_for_serialization(v),
+            b.dfg.value_alias_dest_for_serialization(v),
+        );
+        ensure!(dest_a == dest_b, "{v}: alias {dest_a:?} != {dest_b:?}");
+        if dest_a.is_none() {
+            ensure!(
+                a.dfg.value_def(v) == b.dfg.value_def(v),
+                "{v}: definitions differ"
+            );
+        }
+        ensure!(a.dfg.facts[v] == b.dfg.facts[v], "{v}: facts differ");
+    }
+
+    let (blocks_a, blocks_b): (Vec<_>, Vec<_>) =
+        (a.layout.blocks().collect(), b.layout.blocks().collect());
+    ensure!(blocks_a == blocks_b, "block order differs");
+    for block in blocks_a {
+        ensure!(
+            a.dfg.block_params(block) == b.dfg.block_params(block),
+            "{block}: parameters differ"
+        );
+        ensure!(
+            a.layout.is_cold(block) == b.layout.is_cold(block),
+            "{block}: cold flag differs"
+        );
+
+        let (insts_a, insts_b): (Vec<_>, Vec<_>) = (
+            a.layout.block_insts(block).collect(),
+            b.layout.block_insts(block).collect(),
+        );
+        ensure!(insts_a == insts_b, "{block}: instructions differ");
+        for inst in insts_a {
+            compare_insts(a, b, inst).with_context(|| {
+                format!(
+                    "{inst}: {} != {}",
+                    a.dfg.display_inst(inst),
+                    b.dfg.display_inst(inst)
+                )
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the instruction `inst` is the same in `a` and `b`.
+fn compare_insts(a: &Function, b: &Function, inst: Inst) -> Result<()> {
+    let (data_a, data_b) = (&a.dfg.insts[inst], &b.dfg.insts[inst]);
+    ensure!(data_a.opcode() == data_b.opcode(), "opcodes differ");
+    ensure!(
+        a.dfg.inst_args(inst) == b.dfg.inst_args(inst),
+        "arguments differ"
+    );
+    ensure!(
+        a.dfg.inst_results(inst) == b.dfg.inst_results(inst),
+        "results differ"
+    );
+    ensure!(a.srcloc(inst) == b.srcloc(inst), "source locations differ");
+
+    let (dests_a, dests_b) = (
+        data_a.branch_destination(&a.dfg.jump_tables),
+        data_b.branch_destination(&b.dfg.jump_tables),
+    );
+    ensure!(
+        dests_a.len() == dests_b.len(),
+        "number of branch destinations differs"
+    );
+    for (dest_a, dest_b) in dests_a.iter().zip(dests_b) {
+        ensure!(
+            dest_a.block(&a.dfg.value_lists) == dest_b.block(&b.dfg.value_lists)
+                && dest_a.args_slice(&a.dfg.value_lists) == dest_b.args_slice(&b.dfg.value_lists),
+            "branch destinations differ"
+        );
+    }
+
+    // The instruction data holds references into the value list pool, which differ between the
+    // two functions, so compare the remaining operands, such as immediates and flags, as printed.
+    ensure!(
+        a.dfg.display_inst(inst).to_string() == b.dfg.display_inst(inst).to_string(),
+        "immediates differ"
+    );
+    Ok(())
+}
+
+/// Check that two global values are the same, looking through the external name tables.
+fn same_global_value(
+    a: &Function,
+    gv_a: &GlobalValueData,
+    b: &Function,
+    gv_b: &GlobalValueData,
+) -> bool {
+    match (gv_a, gv_b) {
+        (
+            GlobalValueData::Symbol {
+                name: name_a,
+                offset: offset_a,
+                colocated: colocated_a,
+                tls: tls_a,
+            },
+            GlobalValueData::Symbol {
+                name: name_b,
+                offset: offset_b,
+                colocated: colocated_b,
+                tls: tls_b,
+            },
+        ) => {
+            same_name(a, name_a, b, name_b)
+                && (offset_a, colocated_a, tls_a) == (offset_b, colocated_b, tls_b)
+        }
+        _ => gv_a == gv_b,
+    }
+}
+
+/// Check that two external names are the same.
+///
+/// User names are references into each function's own table, so compare what they refer to.
+fn same_name(a: &Function, name_a: &ExternalName, b: &Function, name_b: &ExternalName) -> bool {
+    match (name_a, name_b) {
+        (ExternalName::User(ref_a), ExternalName::User(ref_b)) => {
+            a.params.user_named_funcs()[*ref_a] == b.params.user_named_funcs()[*ref_b]
+        }
+        _ => name_a == name_b,
+    }
+}
@@ -859,51 +859,56 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             );
         }
         Operator::V128Load8x8S { memarg } => {
-            //TODO(#6829): add before_load() and before_store() hooks for SIMD loads and stores.
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().sload8x8(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load8x8U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().uload8x8(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load16x4S { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().sload16x4(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load16x4U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().uload16x4(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load32x2S { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().sload32x2(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load32x2U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, wasm_index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            environ.before_load(builder, 8, wasm_index, memarg.offset);
             let loaded = builder.ins().uload32x2(flags, base, 0);
             state.push1(loaded);
         }
@@ -3033,16 +3038,13 @@ fn translate_atomic_rmw<FE: FuncEnvironment + ?Sized>(
         arg2 = builder.ins().ireduce(access_ty, arg2);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, wasm_index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    environ.before_load(builder, access_size, wasm_index, memarg.offset);
+    environ.before_store(builder, access_size, wasm_index, memarg.offset);
 
     let mut res = builder.ins().atomic_rmw(access_ty, flags, op, addr, arg2);
     if access_ty != widened_ty {
@@ -3090,16 +3092,13 @@ fn translate_atomic_cas<FE: FuncEnvironment + ?Sized>(
         replacement = builder.ins().ireduce(access_ty, replacement);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, wasm_index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    environ.before_load(builder, access_size, wasm_index, memarg.offset);
+    environ.before_store(builder, access_size, wasm_index, memarg.offset);
     let mut res = builder.ins().atomic_cas(flags, addr, expected, replacement);
     if access_ty != widened_ty {
         res = builder.ins().uextend(widened_ty, res);
@@ -3133,16 +3132,12 @@ fn translate_atomic_load<FE: FuncEnvironment + ?Sized>(
     };
     assert!(w_ty_ok && widened_ty.bytes() >= access_ty.bytes());
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, wasm_index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    environ.before_load(builder, access_size, wasm_index, memarg.offset);
     let mut res = builder.ins().atomic_load(access_ty, flags, addr);
     if access_ty != widened_ty {
         res = builder.ins().uextend(widened_ty, res);
@@ -3182,16 +3177,12 @@ fn translate_atomic_store<FE: FuncEnvironment + ?Sized>(
         data = builder.ins().ireduce(access_ty, data);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, wasm_index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    environ.before_store(builder, access_size, wasm_index, memarg.offset);
     builder.ins().atomic_store(flags, data, addr);
     Ok(())
 }
@@ -3685,3 +3676,91 @@ fn bitcast_wasm_params<FE: FuncEnvironment + ?Sized>(
         *arg = builder.ins().bitcast(t, flags, *arg);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::environ::dummy::{DummyEnvironment, MemoryAccess};
+    use crate::translate_module;
+    use cranelift_codegen::isa::{CallConv, TargetFrontendConfig};
+    use std::vec::Vec;
+    use target_lexicon::PointerWidth;
+
+    fn memory_accesses(wat: &str) -> Vec<MemoryAccess> {
+        let wasm = wat::parse_str(wat).unwrap();
+        let mut env = DummyEnvironment::new(TargetFrontendConfig {
+            default_call_conv: CallConv::SystemV,
+            pointer_width: PointerWidth::U64,
+        });
+        translate_module(&wasm, &mut env).unwrap();
+        env.memory_accesses
+    }
+
+    #[test]
+    fn before_load_and_store() {
+        let accesses = memory_accesses(
+            r#"
+            (module
+              (memory 1)
+              (func (param i32)
+                (i32.store offset=4 (local.get 0) (i32.load8_u offset=1 (local.get 0)))
+                (i64.store16 (local.get 0) (i64.load offset=24 (local.get 0)))
+                (drop (v128.load32x2_s offset=16 (local.get 0)))
+                (v128.store offset=32 (local.get 0) (v128.load64_splat (local.get 0)))))
+            "#,
+        );
+        assert_eq!(
+            accesses,
+            [
+                MemoryAccess::Load { size: 1, offset: 1 },
+                MemoryAccess::Store { size: 4, offset: 4 },
+                MemoryAccess::Load {
+                    size: 8,
+                    offset: 24
+                },
+                MemoryAccess::Store { size: 2, offset: 0 },
+                MemoryAccess::Load {
+                    size: 8,
+                    offset: 16
+                },
+                MemoryAccess::Load { size: 8, offset: 0 },
+                MemoryAccess::Store {
+                    size: 16,
+                    offset: 32
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn before_load_and_store_atomics() {
+        let accesses = memory_accesses(
+            r#"
+            (module
+              (memory 1 1 shared)
+              (func (param i32)
+                (drop (i32.atomic.load offset=4 (local.get 0)))
+                (i64.atomic.store8 offset=1 (local.get 0) (i64.const 0))
+                (drop (i64.atomic.rmw32.add_u offset=8 (local.get 0) (i64.const 1)))
+                (drop (i32.atomic.rmw.cmpxchg offset=12 (local.get 0) (i32.const 0) (i32.const 1)))))
+            "#,
+        );
+        assert_eq!(
+            accesses,
+            [
+                MemoryAccess::Load { size: 4, offset: 4 },
+                MemoryAccess::Store { size: 1, offset: 1 },
+                // Read-modify-write operations both load and store.
+                MemoryAccess::Load { size: 4, offset: 8 },
+                MemoryAccess::Store { size: 4, offset: 8 },
+                MemoryAccess::Load {
+                    size: 4,
+                    offset: 12
+                },
+                MemoryAccess::Store {
+                    size: 4,
+                    offset: 12
+                },
+            ]
+        );
+    }
+}
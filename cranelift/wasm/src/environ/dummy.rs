@@ -8,9 +8,9 @@
 use crate::environ::{FuncEnvironment, GlobalVariable, ModuleEnvironment, TargetEnvironment};
 use crate::func_translator::FuncTranslator;
 use crate::{
-    DataIndex, DefinedFuncIndex, ElemIndex, FuncIndex, Global, GlobalIndex, GlobalInit, Heap,
-    HeapData, HeapStyle, Memory, MemoryIndex, Table, TableIndex, TableSize, TypeConvert, TypeIndex,
-    WasmFuncType, WasmHeapType, WasmResult,
+    ConstExpr, DataIndex, DefinedFuncIndex, ElemIndex, EngineOrModuleTypeIndex, FuncIndex, Global,
+    GlobalIndex, Heap, HeapData, HeapStyle, Memory, MemoryIndex, Table, TableIndex, TableSize,
+    TypeConvert, TypeIndex, WasmFuncType, WasmHeapType, WasmResult,
 };
 use crate::{TableData, WasmValType};
 use cranelift_codegen::cursor::FuncCursor;
@@ -105,6 +105,16 @@ impl DummyModuleInfo {
     }
 }
 
+/// A linear memory access reported to `FuncEnvironment::before_load` or
+/// `FuncEnvironment::before_store`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryAccess {
+    /// A load of `size` bytes at a static `offset`.
+    Load { size: u8, offset: u64 },
+    /// A store of `size` bytes at a static `offset`.
+    Store { size: u8, offset: u64 },
+}
+
 /// This `ModuleEnvironment` implementation is a "naïve" one, doing essentially nothing and
 /// emitting placeholders when forced to. Don't try to execute code translated for this
 /// environment, essentially here for translation debug purposes.
@@ -121,8 +131,8 @@ pub struct DummyEnvironment {
     /// Name of the module from the wasm file.
     pub module_name: Option<String>,
 
-    /// Function names.
-    function_names: SecondaryMap<FuncIndex, String>,
+    /// Memory accesses of all translated functions, in translation order.
+    pub memory_accesses: Vec<MemoryAccess>,
 }
 
 impl DummyEnvironment {
@@ -133,16 +143,10 @@ impl DummyEnvironment {
             trans: FuncTranslator::new(),
             func_bytecode_sizes: Vec::new(),
             module_name: None,
-            function_names: SecondaryMap::new(),
+            memory_accesses: Vec::new(),
         }
     }
 
-    /// Return a `DummyFuncEnvironment` for translating functions within this
-    /// `DummyEnvironment`.
-    pub fn func_env(&self) -> DummyFuncEnvironment {
-        DummyFuncEnvironment::new(&self.info)
-    }
-
     /// Get the type for the function at the given index.
     pub fn get_func_type(&self, func_index: FuncIndex) -> TypeIndex {
         self.info.functions[func_index].entity
@@ -152,12 +156,6 @@ impl DummyEnvironment {
     pub fn get_num_func_imports(&self) -> usize {
         self.info.imported_funcs.len()
     }
-
-    /// Return the name of the function, if a name for the function with
-    /// the corresponding index exists.
-    pub fn get_func_name(&self, func_index: FuncIndex) -> Option<&str> {
-        self.function_names.get(func_index).map(String::as_ref)
-    }
 }
 
 /// The `FuncEnvironment` implementation for use by the `DummyEnvironment`.
@@ -170,6 +168,9 @@ pub struct DummyFuncEnvironment<'dummy_environment> {
 
     /// Cranelift tables we have created to implement Wasm tables.
     tables: SecondaryMap<TableIndex, Option<TableData>>,
+
+    /// Memory accesses reported by the translator, in order.
+    pub memory_accesses: Vec<MemoryAccess>,
 }
 
 impl<'dummy_environment> DummyFuncEnvironment<'dummy_environment> {
@@ -179,6 +180,7 @@ impl<'dummy_environment> DummyFuncEnvironment<'dummy_environment> {
             mod_info,
             heaps: Default::default(),
             tables: Default::default(),
+            memory_accesses: Vec::new(),
         }
     }
 
@@ -245,6 +247,10 @@ impl<'dummy_environment> TypeConvert for DummyFuncEnvironment<'dummy_environment
     fn lookup_heap_type(&self, _index: wasmparser::UnpackedIndex) -> WasmHeapType {
         unimplemented!()
     }
+
+    fn lookup_type_index(&self, _index: wasmparser::UnpackedIndex) -> EngineOrModuleTypeIndex {
+        unimplemented!()
+    }
 }
 
 impl<'dummy_environment> TargetEnvironment for DummyFuncEnvironment<'dummy_environment> {
@@ -646,6 +652,32 @@ impl<'dummy_environment> FuncEnvironment for DummyFuncEnvironment<'dummy_environ
         Err(wasm_unsupported!("ref.i31"))
     }
 
+    fn before_load(
+        &mut self,
+        _builder: &mut FunctionBuilder,
+        val_size: u8,
+        _addr: ir::Value,
+        offset: u64,
+    ) {
+        self.memory_accesses.push(MemoryAccess::Load {
+            size: val_size,
+            offset,
+        });
+    }
+
+    fn before_store(
+        &mut self,
+        _builder: &mut FunctionBuilder,
+        val_size: u8,
+        _addr: ir::Value,
+        offset: u64,
+    ) {
+        self.memory_accesses.push(MemoryAccess::Store {
+            size: val_size,
+            offset,
+        });
+    }
+
     fn translate_i31_get_s(
         &mut self,
         _pos: FuncCursor,
@@ -667,6 +699,10 @@ impl TypeConvert for DummyEnvironment {
     fn lookup_heap_type(&self, _index: wasmparser::UnpackedIndex) -> WasmHeapType {
         unimplemented!()
     }
+
+    fn lookup_type_index(&self, _index: wasmparser::UnpackedIndex) -> EngineOrModuleTypeIndex {
+        unimplemented!()
+    }
 }
 
 impl TargetEnvironment for DummyEnvironment {
@@ -730,7 +766,7 @@ impl<'data> ModuleEnvironment<'data> for DummyEnvironment {
         Ok(())
     }
 
-    fn declare_global(&mut self, global: Global, _init: GlobalInit) -> WasmResult<()> {
+    fn declare_global(&mut self, global: Global, _init: ConstExpr) -> WasmResult<()> {
         self.info.globals.push(Exportable::new(global));
         Ok(())
     }
@@ -886,6 +922,8 @@ impl<'data> ModuleEnvironment<'data> for DummyEnvironment {
 
             self.trans
                 .translate_body(&mut validator, body, &mut func, &mut func_environ)?;
+            self.memory_accesses
+                .append(&mut func_environ.memory_accesses);
             func
         };
         self.info.function_bodies.push(func);
@@ -896,10 +934,6 @@ impl<'data> ModuleEnvironment<'data> for DummyEnvironment {
         self.module_name = Some(String::from(name));
     }
 
-    fn declare_func_name(&mut self, func_index: FuncIndex, name: &'data str) {
-        self.function_names[func_index] = String::from(name);
-    }
-
     fn wasm_features(&self) -> WasmFeatures {
         WasmFeatures::default()
     }
@@ -1,5 +1,7 @@
 //! Support for configurable wasm translation.
 
+#[cfg(test)]
+pub(crate) mod dummy;
 #[macro_use]
 mod spec;
 
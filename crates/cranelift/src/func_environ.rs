@@ -2086,7 +2086,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                         base: ptr,
                         offset: Offset32::new(current_length_offset),
                         global_type: pointer_type,
-                        flags: MemFlags::trusted(),
+                        flags: MemFlags::trusted().with_alias_region(Some(ir::AliasRegion::Vmctx)),
                     });
 
                     let (base_fact, data_mt) = if let Some(ptr_memtype) = ptr_memtype {
@@ -2216,7 +2216,13 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                 }
             };
 
-        let mut flags = MemFlags::trusted().with_checked();
+        // The base and bound live in the `VMMemoryDefinition` and are only
+        // ever written by the runtime, never by compiled code. Putting them
+        // in the vmctx alias region means stores to globals and other
+        // untagged state don't force them to be reloaded.
+        let mut flags = MemFlags::trusted()
+            .with_checked()
+            .with_alias_region(Some(ir::AliasRegion::Vmctx));
         if readonly_base {
             flags.set_readonly();
         }
@@ -2413,6 +2419,8 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         let vmctx = self.vmctx(&mut pos.func);
         let is_shared = self.module.memory_plans[index].memory.shared;
         let base = pos.ins().global_value(pointer_type, vmctx);
+        // Must match the alias region of the heap bound in `make_heap`.
+        let vmctx_flags = ir::MemFlags::trusted().with_alias_region(Some(ir::AliasRegion::Vmctx));
         let current_length_in_bytes = match self.module.defined_memory_index(index) {
             Some(def_index) => {
                 if is_shared {
@@ -2443,8 +2451,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                             .vmctx_vmmemory_definition_current_length(owned_index),
                     )
                     .unwrap();
-                    pos.ins()
-                        .load(pointer_type, ir::MemFlags::trusted(), base, offset)
+                    pos.ins().load(pointer_type, vmctx_flags, base, offset)
                 }
             }
             None => {
@@ -2465,7 +2472,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                 } else {
                     pos.ins().load(
                         pointer_type,
-                        ir::MemFlags::trusted(),
+                        vmctx_flags,
                         vmmemory_ptr,
                         i32::from(self.offsets.ptr.vmmemory_definition_current_length()),
                     )
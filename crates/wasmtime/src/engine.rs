@@ -343,6 +343,8 @@ impl Engine {
             "enable_heap_access_spectre_mitigation"
             | "enable_table_access_spectre_mitigation"
             | "enable_nan_canonicalization"
+            | "enable_fma_contraction"
+            | "float_mode"
             | "enable_jump_tables"
             | "enable_float"
             | "enable_verifier"
@@ -351,6 +353,7 @@ impl Engine {
             | "regalloc_verbose_logs"
             | "is_pic"
             | "bb_padding_log2_minus_one"
+            | "max_insts_log2"
             | "machine_code_cfg_info"
             | "tls_model" // wasmtime doesn't use tls right now
             | "opt_level" // opt level doesn't change semantics
@@ -40,12 +40,12 @@
 ;;     gv1 = load.i64 notrap aligned readonly gv0+8
 ;;     gv2 = load.i64 notrap aligned gv1
 ;;     gv3 = vmctx
-;;     gv4 = load.i64 notrap aligned gv3+104
-;;     gv5 = load.i64 notrap aligned checked gv3+96
+;;     gv4 = load.i64 notrap aligned vmctx gv3+104
+;;     gv5 = load.i64 notrap aligned checked vmctx gv3+96
 ;;     stack_limit = gv2
 ;;
 ;;                                 block0(v0: i64, v1: i64, v2: i32):
-;; @0047                               v7 = load.i64 notrap aligned v0+104
+;; @0047                               v7 = load.i64 notrap aligned vmctx v0+104
 ;; @0047                               v6 = uextend.i64 v2
 ;; @0047                               v8 = icmp ugt v6, v7
 ;; @0047                               brif v8, block2, block3
@@ -54,7 +54,7 @@
 ;; @0047                               trap heap_oob
 ;;
 ;;                                 block3:
-;; @0047                               v9 = load.i64 notrap aligned checked v0+96
+;; @0047                               v9 = load.i64 notrap aligned checked vmctx v0+96
 ;; @0047                               v10 = iadd v9, v6
 ;; @0047                               v11 = load.i32 little heap v10
 ;; @004c                               brif.i8 v8, block4, block5
@@ -75,7 +75,7 @@
 ;; @0051                               trap heap_oob
 ;;
 ;;                                 block7:
-;; @0051                               v25 = load.i64 notrap aligned checked v0+96
+;; @0051                               v25 = load.i64 notrap aligned checked vmctx v0+96
 ;; @0051                               v26 = iadd v25, v6
 ;; @0051                               v27 = iconst.i64 0x000f_ffff
 ;; @0051                               v28 = iadd v26, v27  ; v27 = 0x000f_ffff
@@ -91,12 +91,12 @@
 ;;     gv1 = load.i64 notrap aligned readonly gv0+8
 ;;     gv2 = load.i64 notrap aligned gv1
 ;;     gv3 = vmctx
-;;     gv4 = load.i64 notrap aligned gv3+104
-;;     gv5 = load.i64 notrap aligned checked gv3+96
+;;     gv4 = load.i64 notrap aligned vmctx gv3+104
+;;     gv5 = load.i64 notrap aligned checked vmctx gv3+96
 ;;     stack_limit = gv2
 ;;
 ;;                                 block0(v0: i64, v1: i64, v2: i32, v3: i32, v4: i32, v5: i32):
-;; @005d                               v7 = load.i64 notrap aligned v0+104
+;; @005d                               v7 = load.i64 notrap aligned vmctx v0+104
 ;; @005d                               v6 = uextend.i64 v2
 ;; @005d                               v8 = icmp ugt v6, v7
 ;; @005d                               brif v8, block2, block3
@@ -105,7 +105,7 @@
 ;; @005d                               trap heap_oob
 ;;
 ;;                                 block3:
-;; @005d                               v9 = load.i64 notrap aligned checked v0+96
+;; @005d                               v9 = load.i64 notrap aligned checked vmctx v0+96
 ;; @005d                               v10 = iadd v9, v6
 ;; @005d                               store.i32 little heap v3, v10
 ;; @0064                               brif.i8 v8, block4, block5
@@ -126,7 +126,7 @@
 ;; @006b                               trap heap_oob
 ;;
 ;;                                 block7:
-;; @006b                               v23 = load.i64 notrap aligned checked v0+96
+;; @006b                               v23 = load.i64 notrap aligned checked vmctx v0+96
 ;; @006b                               v24 = iadd v23, v6
 ;; @006b                               v25 = iconst.i64 0x000f_ffff
 ;; @006b                               v26 = iadd v24, v25  ; v25 = 0x000f_ffff
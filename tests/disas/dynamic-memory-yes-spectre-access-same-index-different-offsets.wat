@@ -36,13 +36,13 @@
 ;;     gv1 = load.i64 notrap aligned readonly gv0+8
 ;;     gv2 = load.i64 notrap aligned gv1
 ;;     gv3 = vmctx
-;;     gv4 = load.i64 notrap aligned gv3+104
-;;     gv5 = load.i64 notrap aligned checked gv3+96
+;;     gv4 = load.i64 notrap aligned vmctx gv3+104
+;;     gv5 = load.i64 notrap aligned checked vmctx gv3+96
 ;;     stack_limit = gv2
 ;;
 ;;                                 block0(v0: i64, v1: i64, v2: i32):
-;; @0047                               v7 = load.i64 notrap aligned v0+104
-;; @0047                               v9 = load.i64 notrap aligned checked v0+96
+;; @0047                               v7 = load.i64 notrap aligned vmctx v0+104
+;; @0047                               v9 = load.i64 notrap aligned checked vmctx v0+96
 ;; @0047                               v6 = uextend.i64 v2
 ;; @0047                               v8 = icmp ugt v6, v7
 ;; @0047                               v11 = iconst.i64 0
@@ -71,13 +71,13 @@
 ;;     gv1 = load.i64 notrap aligned readonly gv0+8
 ;;     gv2 = load.i64 notrap aligned gv1
 ;;     gv3 = vmctx
-;;     gv4 = load.i64 notrap aligned gv3+104
-;;     gv5 = load.i64 notrap aligned checked gv3+96
+;;     gv4 = load.i64 notrap aligned vmctx gv3+104
+;;     gv5 = load.i64 notrap aligned checked vmctx gv3+96
 ;;     stack_limit = gv2
 ;;
 ;;                                 block0(v0: i64, v1: i64, v2: i32, v3: i32, v4: i32, v5: i32):
-;; @005d                               v7 = load.i64 notrap aligned v0+104
-;; @005d                               v9 = load.i64 notrap aligned checked v0+96
+;; @005d                               v7 = load.i64 notrap aligned vmctx v0+104
+;; @005d                               v9 = load.i64 notrap aligned checked vmctx v0+96
 ;; @005d                               v6 = uextend.i64 v2
 ;; @005d                               v8 = icmp ugt v6, v7
 ;; @005d                               v11 = iconst.i64 0
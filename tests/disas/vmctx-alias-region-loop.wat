@@ -0,0 +1,60 @@
+;;! target = "x86_64"
+;;! test = "optimize"
+;;! flags = "-O static-memory-maximum-size=0"
+
+;; The memory base and bound live in the vmctx alias region, so neither the
+;; `global.set` nor the heap store in the loop forces them to be reloaded; the
+;; loads before the loop are reused in every iteration.
+
+(module
+  (memory 1)
+  (global $g (mut i32) (i32.const 0))
+  (func (param i32 i32)
+    (global.set $g (i32.load (local.get 0)))
+    (loop $l
+      (global.set $g (i32.load (local.get 0)))
+      (i32.store (local.get 0) (global.get $g))
+      (br_if $l (local.tee 1 (i32.sub (local.get 1) (i32.const 1))))
+    )
+  )
+)
+
+;; function u0:0(i64 vmctx, i64, i32, i32) fast {
+;;     gv0 = vmctx
+;;     gv1 = load.i64 notrap aligned readonly gv0+8
+;;     gv2 = load.i64 notrap aligned gv1
+;;     gv3 = vmctx
+;;     gv4 = load.i64 notrap aligned vmctx gv3+104
+;;     gv5 = load.i64 notrap aligned checked vmctx gv3+96
+;;     stack_limit = gv2
+;;
+;;                                 block0(v0: i64, v1: i64, v2: i32, v3: i32):
+;; @0028                               v5 = load.i64 notrap aligned vmctx v0+104
+;; @0028                               v7 = load.i64 notrap aligned checked vmctx v0+96
+;; @0028                               v4 = uextend.i64 v2
+;; @0028                               v6 = icmp ugt v4, v5
+;; @0028                               v9 = iconst.i64 0
+;; @0028                               v8 = iadd v7, v4
+;; @0028                               v10 = select_spectre_guard v6, v9, v8  ; v9 = 0
+;; @0028                               v11 = load.i32 little heap v10
+;; @002b                               store notrap aligned table v11, v0+112
+;; @003f                               v33 = iconst.i32 1
+;; @002d                               jump block2(v3)
+;;
+;;                                 block2(v32: i32):
+;; @0031                               v21 = load.i32 little heap v10
+;; @0034                               store notrap aligned table v21, v0+112
+;; @003a                               store little heap v21, v10
+;;                                     v41 = iconst.i32 1
+;;                                     v42 = isub v32, v41  ; v41 = 1
+;; @0044                               brif v42, block2(v42), block4
+;;
+;;                                 block4:
+;; @0046                               jump block3
+;;
+;;                                 block3:
+;; @0047                               jump block1
+;;
+;;                                 block1:
+;; @0047                               return
+;; }